@@ -0,0 +1,58 @@
+use cosmwasm_std::{Api, StdError, StdResult, Uint128};
+use cw_asset::{Asset, AssetInfo, AssetInfoUnchecked};
+
+use crate::Token;
+
+impl From<Token> for AssetInfo {
+    fn from(token: Token) -> Self {
+        match token {
+            Token::Native(denom) => AssetInfo::Native(denom),
+            Token::Cw20(addr) => AssetInfo::Cw20(addr),
+        }
+    }
+}
+
+impl From<Token> for AssetInfoUnchecked {
+    fn from(token: Token) -> Self {
+        match token {
+            Token::Native(denom) => AssetInfoUnchecked::Native(denom),
+            Token::Cw20(addr) => AssetInfoUnchecked::Cw20(addr.to_string()),
+        }
+    }
+}
+
+impl TryFrom<AssetInfo> for Token {
+    type Error = StdError;
+
+    fn try_from(info: AssetInfo) -> StdResult<Self> {
+        match info {
+            AssetInfo::Native(denom) => Ok(Token::Native(denom)),
+            AssetInfo::Cw20(addr) => Ok(Token::Cw20(addr)),
+            #[allow(unreachable_patterns)]
+            info => Err(StdError::generic_err(format!(
+                "Unsupported asset type: {}",
+                info
+            ))),
+        }
+    }
+}
+
+impl Token {
+    /// Validates an [`AssetInfoUnchecked`] against the api and converts it into
+    /// a [`Token`].
+    pub fn from_asset_info_unchecked(api: &dyn Api, info: AssetInfoUnchecked) -> StdResult<Self> {
+        info.check(api, None)
+            .map_err(|e| StdError::generic_err(e.to_string()))?
+            .try_into()
+    }
+
+    /// Returns an [`Asset`] of `amount` of the token.
+    pub fn to_asset(&self, amount: impl Into<Uint128>) -> Asset {
+        Asset::new(AssetInfo::from(self.clone()), amount)
+    }
+
+    /// Converts an [`Asset`] into a [`Token`] and its amount.
+    pub fn from_asset(asset: Asset) -> StdResult<(Self, Uint128)> {
+        Ok((asset.info.try_into()?, asset.amount))
+    }
+}
@@ -0,0 +1,37 @@
+use ::astroport::asset::{Asset, AssetInfo};
+use cosmwasm_std::Uint128;
+
+use crate::Token;
+
+impl From<Token> for AssetInfo {
+    fn from(token: Token) -> Self {
+        match token {
+            Token::Native(denom) => AssetInfo::NativeToken { denom },
+            Token::Cw20(contract_addr) => AssetInfo::Token { contract_addr },
+        }
+    }
+}
+
+impl From<AssetInfo> for Token {
+    fn from(info: AssetInfo) -> Self {
+        match info {
+            AssetInfo::NativeToken { denom } => Token::Native(denom),
+            AssetInfo::Token { contract_addr } => Token::Cw20(contract_addr),
+        }
+    }
+}
+
+impl Token {
+    /// Returns an Astroport [`Asset`] of `amount` of the token.
+    pub fn to_astroport_asset(&self, amount: impl Into<Uint128>) -> Asset {
+        Asset {
+            info: self.clone().into(),
+            amount: amount.into(),
+        }
+    }
+
+    /// Converts an Astroport [`Asset`] into a [`Token`] and its amount.
+    pub fn from_astroport_asset(asset: Asset) -> (Self, Uint128) {
+        (asset.info.into(), asset.amount)
+    }
+}
@@ -0,0 +1,139 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{to_json_binary, Addr, Coin, CosmosMsg, StdResult, WasmMsg};
+
+use crate::extensions::keeper::{KeeperExecuteMsg, KeeperJob};
+
+/// The schedule on which a keeper job should be executed by an automation
+/// network.
+#[cw_serde]
+pub enum JobSchedule {
+    /// Execute the job once, as soon as possible.
+    Once,
+    /// Execute the job every given number of blocks.
+    Blocks(u64),
+    /// Execute the job on the given cron schedule, e.g. `"0 0 * * * *"`.
+    Cron(String),
+}
+
+/// An adapter that registers keeper jobs of a vault with an external
+/// automation network. Implement this trait to add support for networks other
+/// than CronCat.
+pub trait AutomationAdapter {
+    /// Returns the messages that register `job` of the vault at `vault` with
+    /// the automation network, to be executed on `schedule`. `funds` are sent
+    /// along to pay for the executions.
+    fn register_job_msgs(
+        &self,
+        vault: &Addr,
+        job: &KeeperJob,
+        schedule: &JobSchedule,
+        funds: Vec<Coin>,
+    ) -> StdResult<Vec<CosmosMsg>>;
+}
+
+/// The interval of a CronCat task, mirroring `croncat_sdk_tasks::types::Interval`.
+#[cw_serde]
+pub enum CronCatInterval {
+    /// Execute the task once.
+    Once,
+    /// Execute the task in every block.
+    Immediate,
+    /// Execute the task every given number of blocks.
+    Block(u64),
+    /// Execute the task on the given cron schedule.
+    Cron(String),
+}
+
+impl From<&JobSchedule> for CronCatInterval {
+    fn from(schedule: &JobSchedule) -> Self {
+        match schedule {
+            JobSchedule::Once => CronCatInterval::Once,
+            JobSchedule::Blocks(blocks) => CronCatInterval::Block(*blocks),
+            JobSchedule::Cron(cron) => CronCatInterval::Cron(cron.clone()),
+        }
+    }
+}
+
+/// An action of a CronCat task, mirroring `croncat_sdk_tasks::types::Action`.
+#[cw_serde]
+pub struct CronCatAction {
+    /// The message executed by the task.
+    pub msg: CosmosMsg,
+    /// The gas limit of the message. If not set, the default of the CronCat
+    /// deployment is used.
+    pub gas_limit: Option<u64>,
+}
+
+/// The payload of a CronCat task, mirroring the required fields of
+/// `croncat_sdk_tasks::types::TaskRequest`.
+#[cw_serde]
+pub struct CronCatTaskRequest {
+    /// The interval on which the task is executed.
+    pub interval: CronCatInterval,
+    /// Whether the task should be removed if one of its executions fails.
+    pub stop_on_fail: bool,
+    /// The actions executed by the task.
+    pub actions: Vec<CronCatAction>,
+}
+
+/// The ExecuteMsg of the CronCat tasks contract, containing only the variant
+/// needed to create tasks.
+#[cw_serde]
+pub enum CronCatExecuteMsg {
+    /// Create a new task.
+    CreateTask {
+        /// The task to create.
+        task: Box<CronCatTaskRequest>,
+    },
+}
+
+/// An [`AutomationAdapter`] registering keeper jobs as CronCat tasks.
+#[cw_serde]
+pub struct CronCatAdapter {
+    /// The address of the CronCat tasks contract.
+    pub tasks_addr: Addr,
+    /// The gas limit of each execution of a job. If not set, the default of
+    /// the CronCat deployment is used.
+    pub gas_limit: Option<u64>,
+}
+
+impl CronCatAdapter {
+    /// Returns the CronCat task that executes `job` of the vault at `vault` on
+    /// `schedule`.
+    pub fn task_request(
+        &self,
+        vault: &Addr,
+        job: &KeeperJob,
+        schedule: &JobSchedule,
+    ) -> StdResult<CronCatTaskRequest> {
+        Ok(CronCatTaskRequest {
+            interval: schedule.into(),
+            stop_on_fail: false,
+            actions: vec![CronCatAction {
+                msg: KeeperExecuteMsg::ExecuteJob { job_id: job.id }
+                    .into_cosmos_msg(vault.to_string(), vec![])?,
+                gas_limit: self.gas_limit,
+            }],
+        })
+    }
+}
+
+impl AutomationAdapter for CronCatAdapter {
+    fn register_job_msgs(
+        &self,
+        vault: &Addr,
+        job: &KeeperJob,
+        schedule: &JobSchedule,
+        funds: Vec<Coin>,
+    ) -> StdResult<Vec<CosmosMsg>> {
+        let task = self.task_request(vault, job, schedule)?;
+        Ok(vec![WasmMsg::Execute {
+            contract_addr: self.tasks_addr.to_string(),
+            msg: to_json_binary(&CronCatExecuteMsg::CreateTask {
+                task: Box::new(task),
+            })?,
+            funds,
+        }
+        .into()])
+    }
+}
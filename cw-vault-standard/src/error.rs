@@ -0,0 +1,80 @@
+use cosmwasm_std::{StdError, Timestamp, Uint128};
+use thiserror::Error;
+
+/// Standard errors that vaults adhering to the vault standard should return
+/// when the conditions specified on the standard messages are violated.
+#[derive(Error, Debug, PartialEq)]
+pub enum VaultStandardError {
+    /// Wraps a [`StdError`].
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    /// Returned when the amount of tokens returned by an action is lower than
+    /// the minimum amount requested by the caller, e.g. `min_shares_out` on
+    /// `Deposit` or `min_assets_out` on `Redeem`.
+    #[error("Slippage exceeded: expected at least {min_out}, got {actual}")]
+    MinOutputNotMet {
+        /// The minimum amount of tokens requested by the caller.
+        min_out: Uint128,
+        /// The actual amount of tokens returned by the action.
+        actual: Uint128,
+    },
+
+    /// Returned when the amount of tokens deposited or redeemed is lower than
+    /// the `min_deposit` or `min_redeem` of the vault, or is zero.
+    #[error("Amount {amount} is below the minimum of {min}")]
+    BelowMinimum {
+        /// The minimum amount of tokens accepted by the vault.
+        min: Uint128,
+        /// The amount of tokens deposited or redeemed.
+        amount: Uint128,
+    },
+
+    /// Returned when the amount of tokens sent in the funds does not match the
+    /// amount specified in the message, e.g. the `amount` field of `Deposit`.
+    #[error("Funds mismatch: expected {expected}{denom}, got {actual}{denom}")]
+    FundsMismatch {
+        /// The denom of the tokens.
+        denom: String,
+        /// The amount of tokens specified in the message.
+        expected: Uint128,
+        /// The amount of tokens actually sent.
+        actual: Uint128,
+    },
+
+    /// Returned when native funds are expected for a token that is a cw20
+    /// token, which must instead be sent via a cw20 `Send` message.
+    #[error("Token {token} is a cw20 token and must be sent via Receive")]
+    ExpectedCw20Receive {
+        /// The cw20 token, in its canonical string format.
+        token: String,
+    },
+
+    /// Returned when funds of other tokens than the expected one were sent.
+    #[error("Unexpected funds sent: {denoms:?}")]
+    UnexpectedFunds {
+        /// The denoms of the unexpected funds.
+        denoms: Vec<String>,
+    },
+
+    /// Returned when the recipient of a deposit does not hold the credential
+    /// required by a vault that enables the Compliance extension.
+    #[error("Address {address} is not compliant")]
+    NonCompliant {
+        /// The non-compliant address.
+        address: String,
+    },
+
+    /// Returned when a message is executed after the `deadline` set by the
+    /// caller.
+    #[error("Deadline exceeded: deadline {deadline}, block time {block_time}")]
+    DeadlineExceeded {
+        /// The deadline set by the caller.
+        deadline: Timestamp,
+        /// The time of the block in which the message was executed.
+        block_time: Timestamp,
+    },
+}
+
+/// Type alias for a `Result` with a [`VaultStandardError`] error.
+pub type VaultStandardResult<T> = Result<T, VaultStandardError>;
@@ -0,0 +1,73 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{
+    to_json_binary, Coin, CosmosMsg, Decimal, StdResult, Timestamp, Uint128, WasmMsg,
+};
+
+use crate::{ExtensionExecuteMsg, VaultStandardExecuteMsg};
+
+/// Additional ExecuteMsg variants for vaults that enable the Accounting
+/// extension.
+#[cw_serde]
+pub enum AccountingExecuteMsg {
+    /// Record a share price checkpoint at the current block. Callable by
+    /// anyone, e.g. keepers. Vaults must return an error if the checkpoint
+    /// interval of the vault has not passed since the last checkpoint. Vaults
+    /// may also record checkpoints automatically, e.g. on deposits.
+    RecordCheckpoint {},
+}
+
+impl AccountingExecuteMsg {
+    /// Convert a [`AccountingExecuteMsg`] into a [`CosmosMsg`].
+    pub fn into_cosmos_msg(self, contract_addr: String, funds: Vec<Coin>) -> StdResult<CosmosMsg> {
+        Ok(WasmMsg::Execute {
+            contract_addr,
+            msg: to_json_binary(&VaultStandardExecuteMsg::VaultExtension(
+                ExtensionExecuteMsg::Accounting(self),
+            ))?,
+            funds,
+        }
+        .into())
+    }
+}
+
+/// Additional QueryMsg variants for vaults that enable the Accounting
+/// extension.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum AccountingQueryMsg {
+    /// Returns the latest `SharePriceCheckpoint` recorded at or before
+    /// `timestamp`. Vaults must return an error if there is no such
+    /// checkpoint.
+    #[returns(SharePriceCheckpoint)]
+    SharePriceAt {
+        /// The block time to query the share price at.
+        timestamp: Timestamp,
+    },
+
+    /// Returns a `Vec<SharePriceCheckpoint>` containing the recorded
+    /// checkpoints, ordered by ID.
+    #[returns(Vec<SharePriceCheckpoint>)]
+    Checkpoints {
+        /// Return results only after this checkpoint ID
+        start_after: Option<u64>,
+        /// Max amount of results to return
+        limit: Option<u32>,
+    },
+}
+
+/// A checkpoint of the share price of the vault.
+#[cw_serde]
+pub struct SharePriceCheckpoint {
+    /// The ID of the checkpoint.
+    pub id: u64,
+    /// The block height at which the checkpoint was recorded.
+    pub height: u64,
+    /// The block time at which the checkpoint was recorded.
+    pub time: Timestamp,
+    /// The total amount of base tokens managed by the vault.
+    pub total_assets: Uint128,
+    /// The total supply of vault tokens.
+    pub total_supply: Uint128,
+    /// The share price, in base tokens per vault token.
+    pub share_price: Decimal,
+}
@@ -0,0 +1,68 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{to_json_binary, Addr, Coin, CosmosMsg, StdResult, WasmMsg};
+use cw_utils::Expiration;
+
+use crate::{ExtensionExecuteMsg, VaultStandardExecuteMsg};
+
+/// Additional ExecuteMsg variants for vaults that enable the Admin extension.
+/// The semantics follow those of the `Action` enum of the
+/// [cw-ownable](https://docs.rs/cw-ownable) crate.
+#[cw_serde]
+pub enum AdminExecuteMsg {
+    /// Propose to transfer ownership of the vault to `new_owner`. Can only be
+    /// called by the current owner. The transfer only takes effect once the
+    /// new owner calls `AcceptOwnership`. Proposing a new transfer overwrites
+    /// any pending one.
+    TransferOwnership {
+        /// The address of the proposed new owner.
+        new_owner: String,
+        /// An optional expiration after which the proposal can no longer be
+        /// accepted.
+        expiry: Option<Expiration>,
+    },
+
+    /// Accept a pending ownership transfer. Can only be called by the pending
+    /// owner, before the expiry of the proposal.
+    AcceptOwnership,
+
+    /// Give up ownership of the vault, leaving it without an owner. Can only be
+    /// called by the current owner. Any pending transfer is cancelled. This
+    /// action is irreversible.
+    RenounceOwnership,
+}
+
+impl AdminExecuteMsg {
+    /// Convert a [`AdminExecuteMsg`] into a [`CosmosMsg`].
+    pub fn into_cosmos_msg(self, contract_addr: String, funds: Vec<Coin>) -> StdResult<CosmosMsg> {
+        Ok(WasmMsg::Execute {
+            contract_addr,
+            msg: to_json_binary(&VaultStandardExecuteMsg::VaultExtension(
+                ExtensionExecuteMsg::Admin(self),
+            ))?,
+            funds,
+        }
+        .into())
+    }
+}
+
+/// Additional QueryMsg variants for vaults that enable the Admin extension.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum AdminQueryMsg {
+    /// Returns `Ownership` containing the current owner of the vault and any
+    /// pending ownership transfer.
+    #[returns(Ownership)]
+    Owner {},
+}
+
+/// Returned by `AdminQueryMsg::Owner` and contains the ownership state of the
+/// vault. Serializes the same as the `Ownership` type of cw-ownable.
+#[cw_serde]
+pub struct Ownership {
+    /// The current owner, or `None` if ownership has been renounced.
+    pub owner: Option<Addr>,
+    /// The proposed new owner, if an ownership transfer is pending.
+    pub pending_owner: Option<Addr>,
+    /// The expiry of the pending ownership transfer, if any.
+    pub pending_expiry: Option<Expiration>,
+}
@@ -0,0 +1,122 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{to_json_binary, Addr, Coin, CosmosMsg, StdResult, Timestamp, Uint128, WasmMsg};
+
+use crate::{ExtensionExecuteMsg, VaultStandardExecuteMsg};
+
+/// Type for the event emitted on call to `RequestRedeem`.
+pub const REDEEM_REQUESTED_EVENT_TYPE: &str = "redeem_requested";
+/// Key for the attribute containing the ID of the created request in the
+/// "redeem requested" event.
+pub const REDEEM_REQUEST_ID_ATTR_KEY: &str = "request_id";
+
+/// Additional ExecuteMsg variants for vaults that enable the AsyncRedeem
+/// extension. The flow is modeled on ERC-7540: a redemption is first
+/// requested, then fulfilled by the vault asynchronously, e.g. once an
+/// unbonding period has passed, and finally claimed.
+#[cw_serde]
+pub enum AsyncRedeemExecuteMsg {
+    /// Request to redeem vault tokens. The native vault tokens must be passed
+    /// in the funds field and are held by the vault until the request is
+    /// claimed. Emits an event with type `REDEEM_REQUESTED_EVENT_TYPE` with an
+    /// attribute with key `REDEEM_REQUEST_ID_ATTR_KEY` containing the u64 ID
+    /// of the request.
+    RequestRedeem {
+        /// The amount of vault tokens to redeem.
+        shares: Uint128,
+        /// An optional field containing which address should own the request
+        /// and be able to claim it. If not set, the caller address will be
+        /// used instead.
+        owner: Option<String>,
+    },
+
+    /// Claim the base tokens of a fulfilled redemption request, see
+    /// `RedeemRequestStatus::Claimable`. Can only be called by the owner of
+    /// the request.
+    ClaimRedeem {
+        /// The ID of the request.
+        request_id: u64,
+        /// An optional field containing which address should receive the
+        /// base tokens. If not set, the caller address will be used instead.
+        recipient: Option<String>,
+    },
+
+    /// Cancel a pending redemption request, returning the vault tokens to the
+    /// owner. Can only be called by the owner of the request. Vaults that do
+    /// not support cancellation, or requests that are already claimable, must
+    /// return an error.
+    CancelRedeem {
+        /// The ID of the request.
+        request_id: u64,
+    },
+}
+
+impl AsyncRedeemExecuteMsg {
+    /// Convert a [`AsyncRedeemExecuteMsg`] into a [`CosmosMsg`].
+    pub fn into_cosmos_msg(self, contract_addr: String, funds: Vec<Coin>) -> StdResult<CosmosMsg> {
+        Ok(WasmMsg::Execute {
+            contract_addr,
+            msg: to_json_binary(&VaultStandardExecuteMsg::VaultExtension(
+                ExtensionExecuteMsg::AsyncRedeem(self),
+            ))?,
+            funds,
+        }
+        .into())
+    }
+}
+
+/// Additional QueryMsg variants for vaults that enable the AsyncRedeem
+/// extension.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum AsyncRedeemQueryMsg {
+    /// Returns the `RedeemRequest` with the given ID.
+    #[returns(RedeemRequest)]
+    RedeemRequest {
+        /// The ID of the request.
+        request_id: u64,
+    },
+
+    /// Returns a `Vec<RedeemRequest>` containing the unclaimed requests of
+    /// `owner`, ordered by ID.
+    #[returns(Vec<RedeemRequest>)]
+    RedeemRequests {
+        /// The owner of the requests.
+        owner: String,
+        /// Return results only after this request ID
+        start_after: Option<u64>,
+        /// Max amount of results to return
+        limit: Option<u32>,
+    },
+
+    /// Returns `Uint128` total amount of vault tokens in pending requests,
+    /// i.e. requests that have not been fulfilled yet.
+    #[returns(Uint128)]
+    TotalPendingRedeem {},
+}
+
+/// The status of a redemption request.
+#[cw_serde]
+pub enum RedeemRequestStatus {
+    /// The request has not been fulfilled yet.
+    Pending,
+    /// The request has been fulfilled and the base tokens can be claimed.
+    Claimable {
+        /// The amount of base tokens that can be claimed.
+        assets: Uint128,
+    },
+}
+
+/// A request to redeem vault tokens.
+#[cw_serde]
+pub struct RedeemRequest {
+    /// The ID of the request.
+    pub id: u64,
+    /// The owner of the request.
+    pub owner: Addr,
+    /// The amount of vault tokens to redeem.
+    pub shares: Uint128,
+    /// The block time at which the request was made.
+    pub requested_at: Timestamp,
+    /// The status of the request.
+    pub status: RedeemRequestStatus,
+}
@@ -0,0 +1,93 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{
+    to_json_binary, Addr, Coin, CosmosMsg, Decimal, StdResult, Timestamp, Uint128, WasmMsg,
+};
+
+use crate::{ExtensionExecuteMsg, TokenAmount, VaultStandardExecuteMsg};
+
+/// Additional ExecuteMsg variants for vaults that enable the Autocompound
+/// extension.
+#[cw_serde]
+pub enum AutocompoundExecuteMsg {
+    /// Harvest the rewards of the vault and reinvest them into the base
+    /// token. Callable by anyone if `CompoundConfig::permissionless` is true,
+    /// otherwise only by the admin or keepers of the vault. The caller
+    /// receives `CompoundConfig::caller_reward` of the harvested rewards.
+    Compound {},
+
+    /// Update the compound configuration of the vault. Can only be called by
+    /// the admin of the vault. Fields that are not set are left unchanged.
+    UpdateCompoundConfig {
+        /// The new fee charged on harvested rewards.
+        compound_fee: Option<Decimal>,
+        /// The new share of the harvested rewards paid to the caller.
+        caller_reward: Option<Decimal>,
+        /// The new recipient of the compound fee.
+        fee_recipient: Option<String>,
+        /// Whether anyone should be able to call `Compound`.
+        permissionless: Option<bool>,
+    },
+}
+
+impl AutocompoundExecuteMsg {
+    /// Convert a [`AutocompoundExecuteMsg`] into a [`CosmosMsg`].
+    pub fn into_cosmos_msg(self, contract_addr: String, funds: Vec<Coin>) -> StdResult<CosmosMsg> {
+        Ok(WasmMsg::Execute {
+            contract_addr,
+            msg: to_json_binary(&VaultStandardExecuteMsg::VaultExtension(
+                ExtensionExecuteMsg::Autocompound(self),
+            ))?,
+            funds,
+        }
+        .into())
+    }
+}
+
+/// Additional QueryMsg variants for vaults that enable the Autocompound
+/// extension.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum AutocompoundQueryMsg {
+    /// Returns an `Option<LastCompoundResponse>` containing information about
+    /// the last call to `Compound`, or `None` if the vault has never been
+    /// compounded.
+    #[returns(Option<LastCompoundResponse>)]
+    LastCompound {},
+
+    /// Returns `CompoundConfig` containing the fees and caller policy of
+    /// `Compound`.
+    #[returns(CompoundConfig)]
+    CompoundConfig {},
+}
+
+/// Returned by `AutocompoundQueryMsg::LastCompound` and contains information
+/// about the last compound of the vault.
+#[cw_serde]
+pub struct LastCompoundResponse {
+    /// The block time of the compound.
+    pub time: Timestamp,
+    /// The block height of the compound.
+    pub height: u64,
+    /// The address that called `Compound`.
+    pub caller: Addr,
+    /// The rewards that were harvested.
+    pub harvested: Vec<TokenAmount>,
+    /// The amount of base tokens added to the vault by reinvesting the
+    /// harvested rewards, after fees.
+    pub compounded: Uint128,
+    /// The reward paid to the caller.
+    pub caller_reward: Vec<TokenAmount>,
+}
+
+/// The configuration of `AutocompoundExecuteMsg::Compound`.
+#[cw_serde]
+pub struct CompoundConfig {
+    /// The fee charged on harvested rewards, as a fraction of the rewards.
+    pub compound_fee: Decimal,
+    /// The share of the harvested rewards paid to the caller of `Compound`.
+    pub caller_reward: Decimal,
+    /// The recipient of the compound fee.
+    pub fee_recipient: Addr,
+    /// Whether anyone can call `Compound`.
+    pub permissionless: bool,
+}
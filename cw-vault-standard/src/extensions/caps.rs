@@ -0,0 +1,112 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{to_json_binary, Addr, Coin, CosmosMsg, StdResult, Uint128, WasmMsg};
+
+use crate::{ExtensionExecuteMsg, VaultStandardExecuteMsg};
+
+/// Additional ExecuteMsg variants for vaults that enable the Caps extension.
+///
+/// The caps must be reflected by the standard queries: `MaxDeposit` must
+/// return the remaining global capacity in `global_remaining` and the
+/// remaining capacity of the recipient in `recipient_remaining`, and
+/// `DepositCapStatus` must report the global cap.
+#[cw_serde]
+pub enum CapsExecuteMsg {
+    /// Update the global deposit cap of the vault, in base tokens. Can only be
+    /// called by the admin of the vault. If `cap` is not set, the vault has no
+    /// global cap.
+    UpdateGlobalCap {
+        /// The new global cap.
+        cap: Option<Uint128>,
+    },
+
+    /// Update the default deposit cap of each address, in base tokens. Can
+    /// only be called by the admin of the vault. If `cap` is not set,
+    /// addresses without an individual cap are not limited.
+    UpdateDefaultAddressCap {
+        /// The new default cap.
+        cap: Option<Uint128>,
+    },
+
+    /// Set an individual deposit cap for the given address, in base tokens,
+    /// overriding the default address cap. Can only be called by the admin of
+    /// the vault. If `cap` is not set, the individual cap is removed and the
+    /// default address cap applies.
+    UpdateAddressCap {
+        /// The address to update the cap of.
+        address: String,
+        /// The new cap of the address.
+        cap: Option<Uint128>,
+    },
+}
+
+impl CapsExecuteMsg {
+    /// Convert a [`CapsExecuteMsg`] into a [`CosmosMsg`].
+    pub fn into_cosmos_msg(self, contract_addr: String, funds: Vec<Coin>) -> StdResult<CosmosMsg> {
+        Ok(WasmMsg::Execute {
+            contract_addr,
+            msg: to_json_binary(&VaultStandardExecuteMsg::VaultExtension(
+                ExtensionExecuteMsg::Caps(self),
+            ))?,
+            funds,
+        }
+        .into())
+    }
+}
+
+/// Additional QueryMsg variants for vaults that enable the Caps extension.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum CapsQueryMsg {
+    /// Returns `CapsResponse` containing the global and default address caps
+    /// of the vault.
+    #[returns(CapsResponse)]
+    Caps {},
+
+    /// Returns `AddressCapResponse` containing the cap that applies to the
+    /// given address and how much of it is used.
+    #[returns(AddressCapResponse)]
+    AddressCap {
+        /// The address to query the cap of.
+        address: String,
+    },
+
+    /// Returns a `Vec<AddressCap>` containing the addresses with an individual
+    /// cap, ordered by address.
+    #[returns(Vec<AddressCap>)]
+    AddressCaps {
+        /// Return results only after this address
+        start_after: Option<String>,
+        /// Max amount of results to return
+        limit: Option<u32>,
+    },
+}
+
+/// Returned by `CapsQueryMsg::Caps` and contains the deposit caps of the
+/// vault, in base tokens.
+#[cw_serde]
+pub struct CapsResponse {
+    /// The global deposit cap, or `None` if the vault has no global cap.
+    pub global_cap: Option<Uint128>,
+    /// The cap of addresses without an individual cap, or `None` if such
+    /// addresses are not limited.
+    pub default_address_cap: Option<Uint128>,
+}
+
+/// Returned by `CapsQueryMsg::AddressCap` and contains the deposit cap of an
+/// address, in base tokens.
+#[cw_serde]
+pub struct AddressCapResponse {
+    /// The cap that applies to the address, or `None` if it is not limited.
+    pub cap: Option<Uint128>,
+    /// The amount of the cap that is used by the deposits of the address.
+    pub used: Uint128,
+}
+
+/// An individual deposit cap of an address.
+#[cw_serde]
+pub struct AddressCap {
+    /// The address.
+    pub address: Addr,
+    /// The cap of the address, in base tokens.
+    pub cap: Uint128,
+}
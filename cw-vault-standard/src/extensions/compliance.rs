@@ -0,0 +1,68 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{to_json_binary, Addr, Coin, CosmosMsg, StdResult, WasmMsg};
+
+use crate::{ExtensionExecuteMsg, VaultStandardExecuteMsg};
+
+/// Additional ExecuteMsg variants for vaults that enable the Compliance
+/// extension.
+///
+/// Vaults that enable this extension must check the recipient of the vault
+/// tokens of every deposit against the compliance checker, by querying it with
+/// [`ComplianceCheckerQueryMsg::IsCompliant`], and fail with
+/// [`crate::VaultStandardError::NonCompliant`] if the check fails.
+#[cw_serde]
+pub enum ComplianceExecuteMsg {
+    /// Set the contract used to check the compliance of depositors. Can only
+    /// be called by the admin of the vault. If `contract` is not set, no
+    /// compliance checks are performed.
+    SetComplianceChecker {
+        /// The address of the compliance checker contract.
+        contract: Option<String>,
+    },
+}
+
+impl ComplianceExecuteMsg {
+    /// Convert a [`ComplianceExecuteMsg`] into a [`CosmosMsg`].
+    pub fn into_cosmos_msg(self, contract_addr: String, funds: Vec<Coin>) -> StdResult<CosmosMsg> {
+        Ok(WasmMsg::Execute {
+            contract_addr,
+            msg: to_json_binary(&VaultStandardExecuteMsg::VaultExtension(
+                ExtensionExecuteMsg::Compliance(self),
+            ))?,
+            funds,
+        }
+        .into())
+    }
+}
+
+/// Additional QueryMsg variants for vaults that enable the Compliance
+/// extension.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum ComplianceQueryMsg {
+    /// Returns an `Option<Addr>` containing the compliance checker contract,
+    /// or `None` if no compliance checks are performed.
+    #[returns(Option<Addr>)]
+    ComplianceChecker {},
+
+    /// Returns `String` type of the credential that depositors must hold, e.g.
+    /// `"kyc"` or `"accredited_investor"`, as understood by the compliance
+    /// checker.
+    #[returns(String)]
+    RequiredCredential {},
+}
+
+/// The QueryMsg that compliance checker contracts must implement.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum ComplianceCheckerQueryMsg {
+    /// Returns bool, whether `address` holds a valid attestation of the given
+    /// credential.
+    #[returns(bool)]
+    IsCompliant {
+        /// The address to check.
+        address: String,
+        /// The type of the credential.
+        credential: String,
+    },
+}
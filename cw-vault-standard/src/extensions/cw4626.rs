@@ -1,14 +1,24 @@
+use std::collections::BTreeMap;
+
 use crate::msg::{
-    ExtensionExecuteMsg, ExtensionQueryMsg, VaultInfoResponse, VaultStandardInfoResponse,
+    AssetAllocation, ConversionDirection, DepositCapStatusResponse, DetailedPreviewResponse,
+    ExtensionExecuteMsg, ExtensionQueryMsg, HistoricalPoint, MaxDepositResponse, MaxRedeemResponse,
+    OperationsStatusResponse, PositionValueResponse, Recipient, Rounding,
+    SupportsExtensionResponse, VaultInfoResponse, VaultStandardInfoResponse,
 };
+#[cfg(feature = "multi-asset")]
+use crate::TokenUnchecked;
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{to_json_binary, Binary, Coin, CosmosMsg, Empty, StdResult, Uint128, WasmMsg};
+use cosmwasm_std::{
+    to_json_binary, Binary, Coin, CosmosMsg, StdResult, Timestamp, Uint128, WasmMsg,
+};
 use cw20::{
     AllAccountsResponse, AllAllowancesResponse, AllowanceResponse, BalanceResponse,
     DownloadLogoResponse, MarketingInfoResponse, TokenInfoResponse,
 };
 use cw20::{Expiration, Logo};
-use schemars::JsonSchema;
+use schemars::schema::RootSchema;
+use schemars::{schema_for, JsonSchema};
 
 /// The default ExecuteMsg variants that a vault using the Cw4626 extension must
 /// implement. This includes all of the variants from the default
@@ -87,8 +97,21 @@ pub enum Cw4626ExecuteMsg<T = ExtensionExecuteMsg> {
         /// The amount of base tokens to deposit
         amount: Uint128,
         /// An optional field containing the recipient of the vault token. If
-        /// not set, the caller address will be used instead.
-        recipient: Option<String>,
+        /// not set, the caller address will be used instead. May carry a
+        /// message to execute on the recipient contract, see [`Recipient`].
+        recipient: Option<Recipient>,
+        /// If set, the vault must dispatch a
+        /// [`crate::VaultCallbackMsg::AfterDeposit`] to the caller after the
+        /// deposit, with its `data` field set to this value.
+        callback: Option<Binary>,
+        /// The minimum amount of vault tokens that must be minted. If fewer
+        /// vault tokens would be minted, the vault must fail with
+        /// [`crate::VaultStandardError::MinOutputNotMet`].
+        min_shares_out: Option<Uint128>,
+        /// An optional deadline for the deposit. If the block time is after
+        /// the deadline, the vault must fail with
+        /// [`crate::VaultStandardError::DeadlineExceeded`].
+        deadline: Option<Timestamp>,
     },
 
     /// Called to redeem vault tokens and receive assets back from the vault.
@@ -100,8 +123,104 @@ pub enum Cw4626ExecuteMsg<T = ExtensionExecuteMsg> {
         amount: Uint128,
         /// An optional field containing which address should receive the
         /// withdrawn base tokens. If not set, the caller address will
-        /// be used instead.
-        recipient: Option<String>,
+        /// be used instead. May carry a message to execute on the recipient
+        /// contract, see [`Recipient`].
+        recipient: Option<Recipient>,
+        /// If set, the vault must dispatch a
+        /// [`crate::VaultCallbackMsg::AfterRedeem`] to the caller after the
+        /// redemption, with its `data` field set to this value.
+        callback: Option<Binary>,
+        /// The minimum amount of base tokens that must be returned. If fewer
+        /// base tokens would be returned, the vault must fail with
+        /// [`crate::VaultStandardError::MinOutputNotMet`].
+        min_assets_out: Option<Uint128>,
+        /// An optional deadline for the redemption. If the block time is after
+        /// the deadline, the vault must fail with
+        /// [`crate::VaultStandardError::DeadlineExceeded`].
+        deadline: Option<Timestamp>,
+    },
+
+    /// Called to withdraw an exact amount of base tokens from the vault. This
+    /// is the base token denominated counterpart of `Redeem`.
+    ///
+    /// The vault must burn the amount of vault tokens returned by
+    /// `QueryMsg::PreviewWithdraw`, rounded up in favor of the vault, from the
+    /// balance of the caller. Must fail if the balance of the caller is not
+    /// enough to withdraw `amount` base tokens.
+    Withdraw {
+        /// The exact amount of base tokens to withdraw.
+        amount: Uint128,
+        /// An optional field containing which address should receive the
+        /// withdrawn base tokens. If not set, the caller address will be
+        /// used instead. May carry a message to execute on the recipient
+        /// contract, see [`Recipient`].
+        recipient: Option<Recipient>,
+    },
+
+    /// Called to mint an exact amount of vault tokens by depositing however
+    /// many base tokens are required. This is the vault token denominated
+    /// counterpart of `Deposit`.
+    ///
+    /// Native base tokens are passed in the funds parameter. The vault must
+    /// deposit the amount of base tokens returned by `QueryMsg::PreviewMint`,
+    /// rounded up in favor of the vault, and refund any excess base tokens to
+    /// the caller. Must fail if the sent base tokens are not enough to mint
+    /// `amount` vault tokens.
+    Mint {
+        /// The exact amount of vault tokens to mint.
+        amount: Uint128,
+        /// The optional recipient of the vault tokens. If not set, the caller
+        /// address will be used instead. May carry a message to execute on
+        /// the recipient contract, see [`Recipient`]. Any refunded base
+        /// tokens are always sent to the caller.
+        recipient: Option<Recipient>,
+    },
+
+    /// Called to deposit all of the base tokens sent to the vault, without
+    /// having to specify an amount. Native base tokens must be passed in the
+    /// funds parameter, and the vault must deposit the entire amount of base
+    /// tokens sent.
+    ///
+    /// Avoids the mismatch between the `amount` field of `Deposit` and the
+    /// actually sent funds. Vaults can use
+    /// [`crate::validation::assert_deposit_funds`] to read the deposited
+    /// amount for both `Deposit` and `DepositAll`.
+    DepositAll {
+        /// The optional recipient of the vault token. If not set, the caller
+        /// address will be used instead. May carry a message to execute on
+        /// the recipient contract, see [`Recipient`].
+        recipient: Option<Recipient>,
+    },
+
+    /// Called to deposit multiple tokens into the vault at once, for vaults
+    /// whose underlying position consists of several tokens, such as an LP
+    /// position or a basket of tokens.
+    ///
+    /// Native tokens are passed in the funds parameter, and the vault must
+    /// fail if the sent funds do not match `assets`. Cw20 tokens must have
+    /// been approved for the vault to spend beforehand.
+    #[cfg(feature = "multi-asset")]
+    DepositMultiple {
+        /// The tokens and amounts to deposit.
+        assets: Vec<(TokenUnchecked, Uint128)>,
+        /// The optional recipient of the vault token. If not set, the caller
+        /// address will be used instead. May carry a message to execute on
+        /// the recipient contract, see [`Recipient`].
+        recipient: Option<Recipient>,
+    },
+
+    /// Called to redeem the entire vault token balance of the caller, without
+    /// having to specify an amount.
+    ///
+    /// Useful for contracts that hold vault tokens and want to fully exit,
+    /// since the amount is read from the balance of the caller and can
+    /// therefore not go stale.
+    RedeemAll {
+        /// An optional field containing which address should receive the
+        /// withdrawn base tokens. If not set, the caller address will be
+        /// used instead. May carry a message to execute on the recipient
+        /// contract, see [`Recipient`].
+        recipient: Option<Recipient>,
     },
 
     /// Called to execute functionality of any enabled extensions.
@@ -126,7 +245,6 @@ impl Cw4626ExecuteMsg {
 /// can be extended with additional variants by defining an extension enum and
 /// then passing it as the generic argument `T` to this enum.
 #[cw_serde]
-#[derive(QueryResponses)]
 pub enum Cw4626QueryMsg<T = ExtensionQueryMsg>
 where
     T: JsonSchema,
@@ -136,33 +254,27 @@ where
     //--------------------------------------------------------------------------
     /// Returns the current balance of the given address, 0 if unset.
     /// Return type: BalanceResponse.
-    #[returns(BalanceResponse)]
     Balance { address: String },
     /// Returns metadata on the contract - name, decimals, supply, etc.
     /// Return type: TokenInfoResponse.
-    #[returns(TokenInfoResponse)]
     TokenInfo {},
     /// Only with "allowance" extension.
     /// Returns how much spender can use from owner account, 0 if unset.
     /// Return type: AllowanceResponse.
-    #[returns(AllowanceResponse)]
     Allowance { owner: String, spender: String },
     /// Only with "marketing" extension
     /// Returns more metadata on the contract to display in the client:
     /// - description, logo, project url, etc.
     /// Return type: MarketingInfoResponse.
-    #[returns(MarketingInfoResponse)]
     MarketingInfo {},
     /// Only with "marketing" extension
     /// Downloads the embedded logo data (if stored on chain). Errors if no logo
     /// data stored for this contract.
     /// Return type: DownloadLogoResponse.
-    #[returns(DownloadLogoResponse)]
     DownloadLogo {},
     /// Only with "enumerable" extension (and "allowances")
     /// Returns all allowances this owner has approved. Supports pagination.
     /// Return type: AllAllowancesResponse.
-    #[returns(AllAllowancesResponse)]
     AllAllowances {
         owner: String,
         start_after: Option<String>,
@@ -171,7 +283,6 @@ where
     /// Only with "enumerable" extension
     /// Returns all accounts that have balances. Supports pagination.
     /// Return type: AllAccountsResponse.
-    #[returns(AllAccountsResponse)]
     AllAccounts {
         start_after: Option<String>,
         limit: Option<u32>,
@@ -182,14 +293,20 @@ where
     //--------------------------------------------------------------------------
     /// Returns `VaultStandardInfoResponse` with information on the version of
     /// the vault standard used as well as any enabled extensions.
-    #[returns(VaultStandardInfoResponse)]
     VaultStandardInfo {},
 
     /// Returns `VaultInfoResponse` representing vault requirements, lockup, &
     /// vault token denom.
-    #[returns(VaultInfoResponse)]
     Info {},
 
+    /// Returns `VaultInfoExtendedResponse` with the information of `Info`, but
+    /// supporting multiple weighted base tokens. Implemented by vaults whose
+    /// underlying position consists of multiple tokens, such as basket and LP
+    /// vaults. Such vaults should still implement `Info` for backwards
+    /// compatibility, e.g. by returning the LP token as the base token.
+    #[cfg(feature = "multi-asset")]
+    InfoExtended {},
+
     /// Returns `Uint128` amount of vault tokens that will be returned for the
     /// passed in `amount` of base tokens.
     ///
@@ -200,7 +317,6 @@ where
     /// tokens that would be minted in a deposit call in the same transaction.
     /// I.e. Deposit should return the same or more vault tokens as
     /// PreviewDeposit if called in the same transaction.
-    #[returns(Uint128)]
     PreviewDeposit {
         /// The amount of base tokens to preview depositing.
         amount: Uint128,
@@ -214,22 +330,74 @@ where
     ///
     /// Must return as close to and no more than the exact amount of base tokens
     /// that would be withdrawn in a redeem call in the same transaction.
-    #[returns(Uint128)]
     PreviewRedeem {
         /// The amount of vault tokens to preview redeeming.
         amount: Uint128,
     },
 
+    /// Returns `Uint128` amount of vault tokens that would be burned in
+    /// exchange for withdrawing exactly `amount` of base tokens.
+    ///
+    /// Allows an on-chain or off-chain user to simulate the effects of their
+    /// withdrawal at the current block, given current on-chain conditions.
+    ///
+    /// Must round up, returning as close to and no fewer than the exact amount
+    /// of vault tokens that would be burned in a withdraw call in the same
+    /// transaction.
+    PreviewWithdraw {
+        /// The amount of base tokens to preview withdrawing.
+        amount: Uint128,
+    },
+
+    /// Returns `Uint128` amount of base tokens that would be needed to mint
+    /// exactly `amount` of vault tokens.
+    ///
+    /// Allows an on-chain or off-chain user to simulate the effects of their
+    /// mint at the current block, given current on-chain conditions.
+    ///
+    /// Must round up, returning as close to and no fewer than the exact amount
+    /// of base tokens that would be deposited in a mint call in the same
+    /// transaction.
+    PreviewMint {
+        /// The amount of vault tokens to preview minting.
+        amount: Uint128,
+    },
+
+    /// Returns `Uint128` amount of vault tokens that will be returned for
+    /// depositing the passed in `assets` via `DepositMultiple`.
+    ///
+    /// Must return as close to and no more than the exact amount of vault
+    /// tokens that would be minted in a `DepositMultiple` call in the same
+    /// transaction.
+    #[cfg(feature = "multi-asset")]
+    PreviewDepositMultiple {
+        /// The tokens and amounts to preview depositing.
+        assets: Vec<(TokenUnchecked, Uint128)>,
+    },
+
     /// Returns the amount of assets managed by the vault denominated in base
     /// tokens. Useful for display purposes, and does not have to confer the
     /// exact amount of base tokens.
-    #[returns(Uint128)]
     TotalAssets {},
 
     /// Returns `Uint128` total amount of vault tokens in circulation.
-    #[returns(Uint128)]
     TotalVaultTokenSupply {},
 
+    /// Returns the exchange rate of vault tokens quoted in terms of the
+    /// supplied quote_denom. Returns a `Decimal` containing the amount of
+    /// `quote_denom` assets that can be exchanged for 1 unit of vault
+    /// tokens.
+    ///
+    /// This allows dashboards and oracles to read the share price directly,
+    /// instead of inferring it from `ConvertToAssets` with a synthetic amount.
+    ///
+    /// May return an error if the quote denom is not supported by the vault.
+    VaultTokenExchangeRate {
+        /// The quote denom to quote the exchange rate in. If not set, the
+        /// exchange rate is quoted in the base token of the vault.
+        quote_denom: Option<String>,
+    },
+
     /// The amount of vault tokens that the vault would exchange for the amount
     /// of assets provided, in an ideal scenario where all the conditions
     /// are met.
@@ -240,10 +408,12 @@ where
     /// price-per-share, and instead should reflect the "average-user’s"
     /// price-per-share, meaning what the average user should expect to see
     /// when exchanging to and from.
-    #[returns(Uint128)]
     ConvertToShares {
         /// The amount of base tokens to convert to vault tokens.
         amount: Uint128,
+        /// The rounding direction to use for the conversion. If not set, the
+        /// vault may choose the rounding direction.
+        rounding: Option<Rounding>,
     },
 
     /// Returns the amount of base tokens that the Vault would exchange for
@@ -256,13 +426,262 @@ where
     /// price-per-share, and instead should reflect the "average-user’s"
     /// price-per-share, meaning what the average user should expect to see
     /// when exchanging to and from.
-    #[returns(Uint128)]
     ConvertToAssets {
         /// The amount of vault tokens to convert to base tokens.
         amount: Uint128,
+        /// The rounding direction to use for the conversion. If not set, the
+        /// vault may choose the rounding direction.
+        rounding: Option<Rounding>,
     },
 
-    /// Handle queries of any enabled extensions.
-    #[returns(Empty)]
+    /// Returns `Uint128` result of converting `amount` between base tokens and
+    /// vault tokens using the exchange rate of the vault at a past block
+    /// height or time.
+    ///
+    /// Only supported by vaults that keep checkpoints of their exchange rate.
+    /// Should use the latest checkpoint at or before `at`, and must return an
+    /// error if the vault has no such checkpoint. Allows analytics tools and
+    /// dispute resolution contracts to reference past exchange rates.
+    HistoricalConvert {
+        /// The amount of tokens to convert.
+        amount: Uint128,
+        /// Whether to convert base tokens to vault tokens or vice versa.
+        direction: ConversionDirection,
+        /// The block height or time at which to use the exchange rate.
+        at: HistoricalPoint,
+    },
+
+    /// Returns `Uint128` maximum amount of base tokens that `owner` could
+    /// withdraw from the vault right now, by redeeming their vault tokens.
+    ///
+    /// Mirrors `maxWithdraw` from ERC-4626. Must take into account any limits
+    /// on withdrawals imposed by the vault, such as lockups or available
+    /// liquidity, and must not be higher than the actual amount of base tokens
+    /// that would be returned. May return 0 if withdrawals are disabled.
+    MaxWithdraw {
+        /// The address of the owner of the vault tokens.
+        owner: String,
+    },
+
+    /// Returns `MaxRedeemResponse` containing both the maximum amount of vault
+    /// tokens that `owner` could redeem right now, and the amount of base
+    /// tokens that redeeming them would return.
+    ///
+    /// Mirrors `maxRedeem` from ERC-4626, but returns both figures from the
+    /// same block so that integrators such as lending protocols don't need to
+    /// combine the results of two separate queries. `assets` must be equal to
+    /// what `MaxWithdraw` would return for the same owner.
+    MaxRedeem {
+        /// The address of the owner of the vault tokens.
+        owner: String,
+    },
+
+    /// Returns `DepositCapStatusResponse` describing the total deposit cap of
+    /// the vault, how much of it is currently used, and the remaining headroom,
+    /// all denominated in base tokens.
+    ///
+    /// Allows integrators routing deposits across multiple vaults to split
+    /// their orders without hitting the cap and reverting.
+    DepositCapStatus {},
+
+    /// Returns `MaxDepositResponse` describing how many base tokens could be
+    /// deposited into the vault for `recipient` right now.
+    ///
+    /// Mirrors `maxDeposit` from ERC-4626, but distinguishes the remaining
+    /// global capacity of the vault from the remaining capacity of the
+    /// recipient, and whether deposits are paused. This lets routers tell a
+    /// protocol wide limit apart from a recipient specific one.
+    MaxDeposit {
+        /// The address that would receive the vault tokens.
+        recipient: String,
+    },
+
+    /// Returns `OperationsStatusResponse` describing which operations of the
+    /// vault are currently enabled, along with an optional reason and resume
+    /// time for any disabled operations.
+    ///
+    /// Allows integrators to grey out actions in their UIs and skip paused
+    /// vaults when routing, without having to simulate transactions.
+    OperationsStatus {},
+
+    /// Returns `PositionValueResponse` containing the vault token balance of
+    /// `owner` along with its value denominated in base tokens, both read at
+    /// the same block.
+    ///
+    /// The base token value should be calculated the same way as
+    /// `ConvertToAssets` would for the owner's vault token balance.
+    PositionValue {
+        /// The address of the owner of the vault tokens.
+        owner: String,
+    },
+
+    /// Returns a `Vec<AssetAllocation>` describing where the assets managed by
+    /// the vault are held, e.g. idle balances held by the vault itself vs.
+    /// amounts deployed in each underlying strategy or position.
+    ///
+    /// The sum of the values of all entries should be consistent with
+    /// `TotalAssets`. Useful for risk dashboards and auditors.
+    TotalAssetsBreakdown {},
+
+    /// Returns `SupportsExtensionResponse` containing whether the vault
+    /// supports the extension with the given `name`, along with the version of
+    /// the extension used by the vault.
+    ///
+    /// Allows routers to feature detect extensions at runtime instead of
+    /// parsing the `extensions` list in `VaultStandardInfoResponse`. The
+    /// names used must match the names in that list, e.g. "lockup".
+    SupportsExtension {
+        /// The name of the extension.
+        name: String,
+    },
+
+    /// Returns `DetailedPreviewResponse` with the amount of vault tokens that
+    /// would be minted in exchange for depositing `amount` of base tokens,
+    /// along with a breakdown of all fees charged.
+    ///
+    /// `gross_amount` is the amount of vault tokens before fees and
+    /// `net_amount` the amount actually minted, which follows the same rules
+    /// as `PreviewDeposit`.
+    PreviewDepositDetailed {
+        /// The amount of base tokens to preview depositing.
+        amount: Uint128,
+    },
+
+    /// Returns `DetailedPreviewResponse` with the amount of base tokens that
+    /// would be returned in exchange for redeeming `amount` of vault tokens,
+    /// along with a breakdown of all fees charged.
+    ///
+    /// `gross_amount` is the amount of base tokens before fees and
+    /// `net_amount` the amount actually returned, which follows the same
+    /// rules as `PreviewRedeem`.
+    PreviewRedeemDetailed {
+        /// The amount of vault tokens to preview redeeming.
+        amount: Uint128,
+    },
+
+    /// Returns a `Vec<Binary>` containing the JSON encoded responses of each of
+    /// the passed in queries, in the same order as the queries.
+    ///
+    /// Allows indexers and frontends to fetch e.g. `Info`, `TotalAssets` and
+    /// `TotalVaultTokenSupply` in a single round trip, with all responses
+    /// reflecting the same block. The entire query must fail if any of the
+    /// batched queries fail. Vaults may reject nested `Batch` queries.
+    ///
+    /// See [`crate::decode_batch_response`] for decoding the responses into
+    /// their typed values.
+    Batch {
+        /// The queries to execute.
+        queries: Vec<Cw4626QueryMsg<T>>,
+    },
+
+    /// Handle queries of any enabled extensions. The responses of the extension
+    /// queries are defined by the `QueryResponses` implementation of `T`.
     VaultExtension(T),
 }
+
+/// Implemented manually like for [`crate::VaultStandardQueryMsg`], so that the
+/// response schemas of the extension queries of `T` are included in place of
+/// the `VaultExtension` variant.
+impl<T> QueryResponses for Cw4626QueryMsg<T>
+where
+    T: QueryResponses,
+{
+    fn response_schemas_impl() -> BTreeMap<String, RootSchema> {
+        let mut responses = BTreeMap::from([
+            ("balance".to_string(), schema_for!(BalanceResponse)),
+            ("token_info".to_string(), schema_for!(TokenInfoResponse)),
+            ("allowance".to_string(), schema_for!(AllowanceResponse)),
+            (
+                "marketing_info".to_string(),
+                schema_for!(MarketingInfoResponse),
+            ),
+            (
+                "download_logo".to_string(),
+                schema_for!(DownloadLogoResponse),
+            ),
+            (
+                "all_allowances".to_string(),
+                schema_for!(AllAllowancesResponse),
+            ),
+            ("all_accounts".to_string(), schema_for!(AllAccountsResponse)),
+            (
+                "vault_standard_info".to_string(),
+                schema_for!(VaultStandardInfoResponse),
+            ),
+            ("info".to_string(), schema_for!(VaultInfoResponse)),
+            ("preview_deposit".to_string(), schema_for!(Uint128)),
+            ("preview_redeem".to_string(), schema_for!(Uint128)),
+            ("preview_withdraw".to_string(), schema_for!(Uint128)),
+            ("preview_mint".to_string(), schema_for!(Uint128)),
+            ("total_assets".to_string(), schema_for!(Uint128)),
+            ("total_vault_token_supply".to_string(), schema_for!(Uint128)),
+            (
+                "vault_token_exchange_rate".to_string(),
+                schema_for!(cosmwasm_std::Decimal),
+            ),
+            ("convert_to_shares".to_string(), schema_for!(Uint128)),
+            ("convert_to_assets".to_string(), schema_for!(Uint128)),
+            ("historical_convert".to_string(), schema_for!(Uint128)),
+            ("max_withdraw".to_string(), schema_for!(Uint128)),
+            ("max_redeem".to_string(), schema_for!(MaxRedeemResponse)),
+            (
+                "deposit_cap_status".to_string(),
+                schema_for!(DepositCapStatusResponse),
+            ),
+            ("max_deposit".to_string(), schema_for!(MaxDepositResponse)),
+            (
+                "operations_status".to_string(),
+                schema_for!(OperationsStatusResponse),
+            ),
+            (
+                "position_value".to_string(),
+                schema_for!(PositionValueResponse),
+            ),
+            (
+                "total_assets_breakdown".to_string(),
+                schema_for!(Vec<AssetAllocation>),
+            ),
+            (
+                "supports_extension".to_string(),
+                schema_for!(SupportsExtensionResponse),
+            ),
+            (
+                "preview_deposit_detailed".to_string(),
+                schema_for!(DetailedPreviewResponse),
+            ),
+            (
+                "preview_redeem_detailed".to_string(),
+                schema_for!(DetailedPreviewResponse),
+            ),
+            ("batch".to_string(), schema_for!(Vec<Binary>)),
+        ]);
+        #[cfg(feature = "multi-asset")]
+        responses.insert(
+            "info_extended".to_string(),
+            schema_for!(crate::msg::VaultInfoExtendedResponse),
+        );
+        #[cfg(feature = "multi-asset")]
+        responses.insert("preview_deposit_multiple".to_string(), schema_for!(Uint128));
+        responses.extend(T::response_schemas_impl());
+        responses
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "lockup")]
+    #[test]
+    fn query_responses_include_extension_queries() {
+        use crate::extensions::lockup::UnlockingPosition;
+
+        let responses = Cw4626QueryMsg::<ExtensionQueryMsg>::response_schemas_impl();
+        assert_eq!(responses["balance"], schema_for!(BalanceResponse));
+        assert_eq!(
+            responses["unlocking_positions"],
+            schema_for!(Vec<UnlockingPosition>)
+        );
+        assert!(!responses.contains_key("vault_extension"));
+    }
+}
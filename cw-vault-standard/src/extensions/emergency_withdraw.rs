@@ -0,0 +1,59 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{to_json_binary, Coin, CosmosMsg, StdResult, Uint128, WasmMsg};
+
+use crate::{ExtensionExecuteMsg, VaultStandardExecuteMsg};
+
+/// Additional ExecuteMsg variants for vaults that enable the EmergencyWithdraw
+/// extension.
+#[cw_serde]
+pub enum EmergencyWithdrawExecuteMsg {
+    /// Redeem vault tokens against the idle reserves of the vault only,
+    /// without touching its strategies. The native vault tokens must be
+    /// passed in the funds field. Vaults must keep this message available
+    /// even when `Redeem` is paused or frozen, and may return fewer base
+    /// tokens than `Redeem` would. Must fail if the idle reserves are not
+    /// sufficient.
+    EmergencyRedeem {
+        /// The amount of vault tokens to redeem.
+        amount: Uint128,
+        /// An optional field containing which address should receive the
+        /// withdrawn base tokens. If not set, the caller address will be used
+        /// instead.
+        recipient: Option<String>,
+    },
+}
+
+impl EmergencyWithdrawExecuteMsg {
+    /// Convert a [`EmergencyWithdrawExecuteMsg`] into a [`CosmosMsg`].
+    pub fn into_cosmos_msg(self, contract_addr: String, funds: Vec<Coin>) -> StdResult<CosmosMsg> {
+        Ok(WasmMsg::Execute {
+            contract_addr,
+            msg: to_json_binary(&VaultStandardExecuteMsg::VaultExtension(
+                ExtensionExecuteMsg::EmergencyWithdraw(self),
+            ))?,
+            funds,
+        }
+        .into())
+    }
+}
+
+/// Additional QueryMsg variants for vaults that enable the EmergencyWithdraw
+/// extension.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum EmergencyWithdrawQueryMsg {
+    /// Returns `Uint128` amount of base tokens held as idle reserves by the
+    /// vault, i.e. the maximum total that can be withdrawn through
+    /// `EmergencyRedeem`.
+    #[returns(Uint128)]
+    EmergencyLiquidity {},
+
+    /// Returns `Uint128` amount of base tokens that would be returned by
+    /// calling `EmergencyWithdrawExecuteMsg::EmergencyRedeem` with the given
+    /// amount of vault tokens in the same transaction.
+    #[returns(Uint128)]
+    PreviewEmergencyRedeem {
+        /// The amount of vault tokens to redeem.
+        amount: Uint128,
+    },
+}
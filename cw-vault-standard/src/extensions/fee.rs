@@ -0,0 +1,106 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{to_json_binary, Addr, Coin, CosmosMsg, Decimal, StdResult, WasmMsg};
+
+use crate::{ExtensionExecuteMsg, VaultStandardExecuteMsg};
+
+/// Additional ExecuteMsg variants for vaults that enable the Fee extension.
+#[cw_serde]
+pub enum FeeExecuteMsg {
+    /// Update the fees charged by the vault. Can only be called by the admin
+    /// of the vault. Fees that are not set are left unchanged. Vaults must
+    /// return an error if any of the new fees exceeds the corresponding bound
+    /// returned by `FeeQueryMsg::FeeBounds`, or if the shares of the
+    /// recipients do not sum up to one.
+    UpdateFees {
+        /// The new fee charged on deposits.
+        deposit_fee: Option<Decimal>,
+        /// The new fee charged on redemptions and withdrawals.
+        withdrawal_fee: Option<Decimal>,
+        /// The new annual management fee.
+        management_fee: Option<Decimal>,
+        /// The new performance fee.
+        performance_fee: Option<Decimal>,
+        /// The new recipients of the fees.
+        recipients: Option<Vec<FeeRecipientUnchecked>>,
+    },
+}
+
+impl FeeExecuteMsg {
+    /// Convert a [`FeeExecuteMsg`] into a [`CosmosMsg`].
+    pub fn into_cosmos_msg(self, contract_addr: String, funds: Vec<Coin>) -> StdResult<CosmosMsg> {
+        Ok(WasmMsg::Execute {
+            contract_addr,
+            msg: to_json_binary(&VaultStandardExecuteMsg::VaultExtension(
+                ExtensionExecuteMsg::Fee(self),
+            ))?,
+            funds,
+        }
+        .into())
+    }
+}
+
+/// Additional QueryMsg variants for vaults that enable the Fee extension.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum FeeQueryMsg {
+    /// Returns `FeesResponse` containing the fees currently charged by the
+    /// vault and their recipients.
+    #[returns(FeesResponse)]
+    Fees {},
+
+    /// Returns `FeeBounds` containing the maximum fees that can be set through
+    /// `FeeExecuteMsg::UpdateFees`.
+    #[returns(FeeBounds)]
+    FeeBounds {},
+}
+
+/// A recipient of a share of the fees charged by the vault.
+#[cw_serde]
+pub struct FeeRecipient {
+    /// The address of the recipient.
+    pub address: Addr,
+    /// The share of the fees received, as a fraction of one.
+    pub share: Decimal,
+}
+
+/// An unchecked [`FeeRecipient`], as passed in `FeeExecuteMsg::UpdateFees`.
+#[cw_serde]
+pub struct FeeRecipientUnchecked {
+    /// The address of the recipient.
+    pub address: String,
+    /// The share of the fees received, as a fraction of one.
+    pub share: Decimal,
+}
+
+/// Returned by `FeeQueryMsg::Fees` and contains the fees charged by the vault.
+/// All fees are fractions, e.g. `0.005` for 50 bps.
+#[cw_serde]
+pub struct FeesResponse {
+    /// The fee charged on deposits, as a fraction of the deposited base
+    /// tokens.
+    pub deposit_fee: Decimal,
+    /// The fee charged on redemptions and withdrawals, as a fraction of the
+    /// withdrawn base tokens.
+    pub withdrawal_fee: Decimal,
+    /// The annual management fee, as a fraction of the total assets of the
+    /// vault.
+    pub management_fee: Decimal,
+    /// The performance fee, as a fraction of the yield of the vault.
+    pub performance_fee: Decimal,
+    /// The recipients of the fees.
+    pub recipients: Vec<FeeRecipient>,
+}
+
+/// Returned by `FeeQueryMsg::FeeBounds` and contains the maximum fees of the
+/// vault.
+#[cw_serde]
+pub struct FeeBounds {
+    /// The maximum fee charged on deposits.
+    pub max_deposit_fee: Decimal,
+    /// The maximum fee charged on redemptions and withdrawals.
+    pub max_withdrawal_fee: Decimal,
+    /// The maximum annual management fee.
+    pub max_management_fee: Decimal,
+    /// The maximum performance fee.
+    pub max_performance_fee: Decimal,
+}
@@ -1,5 +1,5 @@
-use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{to_json_binary, Coin, CosmosMsg, StdResult, Uint128, WasmMsg};
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{to_json_binary, Addr, Coin, CosmosMsg, StdResult, Uint128, WasmMsg};
 
 use crate::{ExtensionExecuteMsg, VaultStandardExecuteMsg};
 
@@ -60,3 +60,19 @@ impl ForceUnlockExecuteMsg {
         .into())
     }
 }
+
+/// Additional QueryMsg variants for vaults that enable the ForceUnlock
+/// extension.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum ForceUnlockQueryMsg {
+    /// Returns a `Vec<Addr>` containing the addresses that are allowed to call
+    /// `ForceRedeem` and `ForceWithdrawUnlocking`.
+    #[returns(Vec<Addr>)]
+    ForceWithdrawWhitelist {
+        /// Return results only after this address
+        start_after: Option<String>,
+        /// Max amount of results to return
+        limit: Option<u32>,
+    },
+}
@@ -0,0 +1,180 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{
+    to_json_binary, Binary, Coin, CosmosMsg, StdResult, Timestamp, Uint128, WasmMsg,
+};
+
+use crate::{ExtensionExecuteMsg, VaultStandardExecuteMsg};
+
+/// Type for the event emitted when an interchain operation is sent.
+pub const INTERCHAIN_OPERATION_SENT_EVENT_TYPE: &str = "interchain_operation_sent";
+/// Type for the event emitted when an interchain operation is acknowledged or
+/// times out.
+pub const INTERCHAIN_OPERATION_SETTLED_EVENT_TYPE: &str = "interchain_operation_settled";
+/// Key for the attribute containing the ID of the operation in the interchain
+/// operation events.
+pub const INTERCHAIN_OPERATION_ID_ATTR_KEY: &str = "operation_id";
+
+/// Additional ExecuteMsg variants for vaults that enable the Ica extension.
+///
+/// Vaults that enable this extension control interchain accounts on remote
+/// chains and deposit into or redeem from a remote strategy through them.
+/// Since the result of an interchain transaction is only known once it is
+/// acknowledged, every deposit and redemption creates a pending
+/// [`InterchainOperation`] that is settled by an [`IcaAcknowledgementMsg`].
+#[cw_serde]
+pub enum IcaExecuteMsg {
+    /// Register an interchain account on the chain of the given connection.
+    /// Can only be called by the admin of the vault.
+    RegisterInterchainAccount {
+        /// The IBC connection to the remote chain.
+        connection_id: String,
+    },
+
+    /// Send base tokens to the interchain account on the given connection and
+    /// deposit them into the remote strategy. Can only be called by the admin
+    /// or keepers of the vault. Emits an event with type
+    /// `INTERCHAIN_OPERATION_SENT_EVENT_TYPE` with an attribute with key
+    /// `INTERCHAIN_OPERATION_ID_ATTR_KEY`.
+    InterchainDeposit {
+        /// The IBC connection to the remote chain.
+        connection_id: String,
+        /// The amount of base tokens to deposit.
+        amount: Uint128,
+        /// The number of seconds after which the interchain transaction times
+        /// out.
+        timeout_seconds: u64,
+    },
+
+    /// Redeem base tokens from the remote strategy through the interchain
+    /// account on the given connection and send them back to the vault. Can
+    /// only be called by the admin or keepers of the vault. Emits an event
+    /// with type `INTERCHAIN_OPERATION_SENT_EVENT_TYPE` with an attribute with
+    /// key `INTERCHAIN_OPERATION_ID_ATTR_KEY`.
+    InterchainRedeem {
+        /// The IBC connection to the remote chain.
+        connection_id: String,
+        /// The amount of base tokens to redeem.
+        amount: Uint128,
+        /// The number of seconds after which the interchain transaction times
+        /// out.
+        timeout_seconds: u64,
+    },
+}
+
+impl IcaExecuteMsg {
+    /// Convert a [`IcaExecuteMsg`] into a [`CosmosMsg`].
+    pub fn into_cosmos_msg(self, contract_addr: String, funds: Vec<Coin>) -> StdResult<CosmosMsg> {
+        Ok(WasmMsg::Execute {
+            contract_addr,
+            msg: to_json_binary(&VaultStandardExecuteMsg::VaultExtension(
+                ExtensionExecuteMsg::Ica(self),
+            ))?,
+            funds,
+        }
+        .into())
+    }
+}
+
+/// The message delivered to the vault when the result of an interchain
+/// operation is known, e.g. through a `Sudo` entry point or an IBC callback
+/// middleware, depending on the chain. Settles the pending operation and
+/// emits an event with type `INTERCHAIN_OPERATION_SETTLED_EVENT_TYPE` with an
+/// attribute with key `INTERCHAIN_OPERATION_ID_ATTR_KEY`.
+#[cw_serde]
+pub enum IcaAcknowledgementMsg {
+    /// The interchain transaction was acknowledged by the remote chain.
+    Acknowledgement {
+        /// The ID of the operation.
+        operation_id: u64,
+        /// The result of the interchain transaction.
+        result: IcaAckResult,
+    },
+    /// The interchain transaction timed out before it was relayed.
+    Timeout {
+        /// The ID of the operation.
+        operation_id: u64,
+    },
+}
+
+/// The result of an acknowledged interchain transaction.
+#[cw_serde]
+pub enum IcaAckResult {
+    /// The transaction succeeded, with the contained response data.
+    Success(Binary),
+    /// The transaction failed with the contained error.
+    Error(String),
+}
+
+/// Additional QueryMsg variants for vaults that enable the Ica extension.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum IcaQueryMsg {
+    /// Returns a `Vec<InterchainAccount>` containing the interchain accounts
+    /// controlled by the vault.
+    #[returns(Vec<InterchainAccount>)]
+    InterchainAccounts {},
+
+    /// Returns the `InterchainOperation` with the given ID.
+    #[returns(InterchainOperation)]
+    InterchainOperation {
+        /// The ID of the operation.
+        operation_id: u64,
+    },
+
+    /// Returns a `Vec<InterchainOperation>` containing the operations that
+    /// have not been settled yet, ordered by ID.
+    #[returns(Vec<InterchainOperation>)]
+    PendingInterchainOperations {
+        /// Return results only after this operation ID
+        start_after: Option<u64>,
+        /// Max amount of results to return
+        limit: Option<u32>,
+    },
+}
+
+/// An interchain account controlled by the vault.
+#[cw_serde]
+pub struct InterchainAccount {
+    /// The IBC connection to the remote chain.
+    pub connection_id: String,
+    /// The address of the account on the remote chain, or `None` if the
+    /// registration has not been acknowledged yet.
+    pub address: Option<String>,
+}
+
+/// The kind of an interchain operation.
+#[cw_serde]
+pub enum InterchainOperationKind {
+    /// A deposit into the remote strategy.
+    Deposit,
+    /// A redemption from the remote strategy.
+    Redeem,
+}
+
+/// The status of an interchain operation.
+#[cw_serde]
+pub enum InterchainOperationStatus {
+    /// The operation has been sent and is waiting for an acknowledgement.
+    Pending,
+    /// The operation was acknowledged with the contained result.
+    Acknowledged(IcaAckResult),
+    /// The operation timed out.
+    TimedOut,
+}
+
+/// A deposit or redemption sent through an interchain account.
+#[cw_serde]
+pub struct InterchainOperation {
+    /// The ID of the operation.
+    pub id: u64,
+    /// The IBC connection to the remote chain.
+    pub connection_id: String,
+    /// The kind of the operation.
+    pub kind: InterchainOperationKind,
+    /// The amount of base tokens deposited or redeemed.
+    pub amount: Uint128,
+    /// The block time at which the operation was sent.
+    pub sent_at: Timestamp,
+    /// The status of the operation.
+    pub status: InterchainOperationStatus,
+}
@@ -0,0 +1,68 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{to_json_binary, Coin, CosmosMsg, Decimal, StdResult, Uint128, WasmMsg};
+
+use crate::{ExtensionExecuteMsg, VaultStandardExecuteMsg};
+
+/// Additional ExecuteMsg variants for vaults that enable the InstantUnlock
+/// extension.
+#[cw_serde]
+pub enum InstantUnlockExecuteMsg {
+    /// Exit a locked or unlocking position immediately, skipping the remaining
+    /// lockup duration. The vault sources the exit liquidity, e.g. from a
+    /// secondary market or a penalty pool, and returns the base tokens minus
+    /// the haircut returned by `InstantUnlockQueryMsg::PreviewInstantUnlock`.
+    /// Can only be called by the owner of the position.
+    InstantUnlock {
+        /// The ID of the position to exit.
+        lockup_id: u64,
+        /// The minimum amount of base tokens the caller is willing to receive.
+        /// Vaults must return an error if the amount after the haircut is
+        /// lower than this.
+        min_out: Uint128,
+        /// An optional field containing which address should receive the
+        /// base tokens. If not set, the caller address will be used instead.
+        recipient: Option<String>,
+    },
+}
+
+impl InstantUnlockExecuteMsg {
+    /// Convert a [`InstantUnlockExecuteMsg`] into a [`CosmosMsg`].
+    pub fn into_cosmos_msg(self, contract_addr: String, funds: Vec<Coin>) -> StdResult<CosmosMsg> {
+        Ok(WasmMsg::Execute {
+            contract_addr,
+            msg: to_json_binary(&VaultStandardExecuteMsg::VaultExtension(
+                ExtensionExecuteMsg::InstantUnlock(self),
+            ))?,
+            funds,
+        }
+        .into())
+    }
+}
+
+/// Additional QueryMsg variants for vaults that enable the InstantUnlock
+/// extension.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum InstantUnlockQueryMsg {
+    /// Returns `InstantUnlockQuote` for exiting the given position through
+    /// `InstantUnlockExecuteMsg::InstantUnlock` in the same transaction.
+    /// Vaults must return an error if no exit liquidity is available.
+    #[returns(InstantUnlockQuote)]
+    PreviewInstantUnlock {
+        /// The ID of the position.
+        lockup_id: u64,
+    },
+}
+
+/// Returned by `InstantUnlockQueryMsg::PreviewInstantUnlock` and contains the
+/// terms of an instant exit from a lockup position.
+#[cw_serde]
+pub struct InstantUnlockQuote {
+    /// The amount of base tokens in the position.
+    pub base_token_amount: Uint128,
+    /// The fraction of the base tokens that is given up to skip the remaining
+    /// lockup duration.
+    pub haircut: Decimal,
+    /// The amount of base tokens that would be received after the haircut.
+    pub amount_out: Uint128,
+}
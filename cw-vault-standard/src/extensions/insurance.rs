@@ -0,0 +1,118 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{
+    to_json_binary, Addr, Coin, CosmosMsg, Decimal, StdResult, Timestamp, Uint128, WasmMsg,
+};
+
+use crate::{ExtensionExecuteMsg, TokenAmount, VaultStandardExecuteMsg};
+
+/// Additional ExecuteMsg variants for vaults that enable the Insurance
+/// extension.
+#[cw_serde]
+pub enum InsuranceExecuteMsg {
+    /// File a claim against the insurance fund for a loss of base tokens
+    /// suffered by the caller as a depositor of the vault.
+    FileClaim {
+        /// The amount of base tokens claimed.
+        amount: Uint128,
+        /// A description of the loss, e.g. a reference to the incident.
+        description: String,
+    },
+
+    /// Approve or reject a pending claim. Can only be called by the admin of
+    /// the vault or the manager of the insurance fund. If `payout` is set, the
+    /// claim is approved and `payout` base tokens are paid to the claimant.
+    /// Otherwise the claim is rejected.
+    ResolveClaim {
+        /// The ID of the claim.
+        claim_id: u64,
+        /// The amount of base tokens paid out.
+        payout: Option<Uint128>,
+    },
+}
+
+impl InsuranceExecuteMsg {
+    /// Convert a [`InsuranceExecuteMsg`] into a [`CosmosMsg`].
+    pub fn into_cosmos_msg(self, contract_addr: String, funds: Vec<Coin>) -> StdResult<CosmosMsg> {
+        Ok(WasmMsg::Execute {
+            contract_addr,
+            msg: to_json_binary(&VaultStandardExecuteMsg::VaultExtension(
+                ExtensionExecuteMsg::Insurance(self),
+            ))?,
+            funds,
+        }
+        .into())
+    }
+}
+
+/// Additional QueryMsg variants for vaults that enable the Insurance extension.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum InsuranceQueryMsg {
+    /// Returns `CoverageResponse` describing the insurance fund attached to
+    /// the vault.
+    #[returns(CoverageResponse)]
+    Coverage {},
+
+    /// Returns the `InsuranceClaim` with the given ID.
+    #[returns(InsuranceClaim)]
+    ClaimStatus {
+        /// The ID of the claim.
+        claim_id: u64,
+    },
+
+    /// Returns a `Vec<InsuranceClaim>` containing the claims filed by
+    /// `claimant`, ordered by ID.
+    #[returns(Vec<InsuranceClaim>)]
+    Claims {
+        /// The address that filed the claims.
+        claimant: String,
+        /// Return results only after this claim ID
+        start_after: Option<u64>,
+        /// Max amount of results to return
+        limit: Option<u32>,
+    },
+}
+
+/// Returned by `InsuranceQueryMsg::Coverage` and describes the insurance fund
+/// attached to the vault.
+#[cw_serde]
+pub struct CoverageResponse {
+    /// The address of the insurance fund.
+    pub fund: Addr,
+    /// The balance of the insurance fund.
+    pub fund_balance: TokenAmount,
+    /// The value of the fund balance as a fraction of the total assets of the
+    /// vault.
+    pub coverage_ratio: Decimal,
+}
+
+/// The status of an insurance claim.
+#[cw_serde]
+pub enum InsuranceClaimStatus {
+    /// The claim has not been resolved yet.
+    Pending,
+    /// The claim was approved and `payout` base tokens were paid out.
+    Approved {
+        /// The amount of base tokens paid out.
+        payout: Uint128,
+    },
+    /// The claim was rejected.
+    Rejected,
+}
+
+/// A claim against the insurance fund.
+#[cw_serde]
+pub struct InsuranceClaim {
+    /// The ID of the claim.
+    pub id: u64,
+    /// The address that filed the claim.
+    pub claimant: Addr,
+    /// The amount of base tokens claimed.
+    pub amount: Uint128,
+    /// A description of the loss.
+    pub description: String,
+    /// The block time at which the claim was filed.
+    pub filed_at: Timestamp,
+    /// The status of the claim.
+    pub status: InsuranceClaimStatus,
+}
@@ -1,7 +1,55 @@
+use std::marker::PhantomData;
+
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{to_json_binary, Addr, Coin, CosmosMsg, StdResult, WasmMsg};
+use cosmwasm_std::{
+    to_json_binary, Addr, Coin, CosmosMsg, Decimal, Event, QuerierWrapper, StdResult, Uint128,
+    WasmMsg,
+};
+use cw_utils::{Duration, Expiration};
+
+use schemars::JsonSchema;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::{
+    ExtensionExecuteMsg, ExtensionQueryMsg, Token, TokenAmount, VaultStandardExecuteMsg,
+    VaultStandardQueryMsg,
+};
 
-use crate::{ExtensionExecuteMsg, VaultStandardExecuteMsg};
+/// Type for the event emitted on every successful call to `ExecuteJob`.
+pub const KEEPER_EXECUTE_EVENT_TYPE: &str = "keeper_execute";
+/// Key for the attribute containing the [`JobOutcome`] in the "keeper execute"
+/// event.
+pub const KEEPER_OUTCOME_ATTR_KEY: &str = "outcome";
+/// Type for the event emitted on call to `ExecuteJob` when a reward is paid
+/// to the keeper.
+pub const KEEPER_REWARD_PAID_EVENT_TYPE: &str = "keeper_reward_paid";
+/// Key for the attribute containing the ID of the executed job in the keeper
+/// events.
+pub const KEEPER_JOB_ID_ATTR_KEY: &str = "job_id";
+/// Key for the attribute containing the address of the keeper in the keeper
+/// events.
+pub const KEEPER_ATTR_KEY: &str = "keeper";
+/// Key for the attribute containing the total reward paid to the keeper,
+/// including any gas refund, in the keeper events.
+pub const KEEPER_REWARD_ATTR_KEY: &str = "reward";
+/// Key for the attribute containing the gas refund component of the reward in
+/// the "keeper reward paid" event.
+pub const KEEPER_GAS_REFUND_ATTR_KEY: &str = "gas_refund";
+
+/// Type for the event emitted on call to `Slash`.
+pub const KEEPER_SLASHED_EVENT_TYPE: &str = "keeper_slashed";
+/// Key for the attribute containing the slashed amount of the bond in the
+/// "keeper slashed" event.
+pub const KEEPER_SLASH_AMOUNT_ATTR_KEY: &str = "amount";
+/// Key for the attribute containing the reason for the slash in the "keeper
+/// slashed" event.
+pub const KEEPER_SLASH_REASON_ATTR_KEY: &str = "reason";
+
+/// Type for the event emitted on call to `Blacklist`.
+pub const KEEPER_BLACKLISTED_EVENT_TYPE: &str = "keeper_blacklisted";
+/// Type for the event emitted on call to `RemoveFromBlacklist`.
+pub const KEEPER_UNBLACKLISTED_EVENT_TYPE: &str = "keeper_unblacklisted";
 
 /// A job that can be performed by a keeper.
 #[cw_serde]
@@ -12,6 +60,81 @@ pub struct KeeperJob {
     pub whitelist: bool,
     /// A list of whitelisted addresses that can execute the job
     pub whitelisted_keepers: Vec<Addr>,
+    /// The priority of the job, where higher values are more urgent. Allows
+    /// automation networks to schedule work across many vaults, see
+    /// [`KeeperQueryMsg::KeeperJobsByUrgency`]. Defaults to zero, so that
+    /// jobs returned by vaults that do not set a priority can still be parsed.
+    #[serde(default)]
+    pub priority: u32,
+}
+
+/// Parameters for refunding the gas spent by keepers as part of their reward.
+#[cw_serde]
+pub struct GasRefundConfig {
+    /// The token the gas refund is paid in
+    pub token: Token,
+    /// The amount of `token` refunded per unit of gas
+    pub gas_price: Decimal,
+    /// The amount of gas that is refunded per execution of a job
+    pub gas_per_execution: u64,
+    /// The maximum amount of `token` refunded per execution of a job
+    pub max_refund: Uint128,
+}
+
+/// The bond that keepers must post before they can execute jobs.
+#[cw_serde]
+pub struct BondRequirement {
+    /// The token and minimum amount that must be bonded
+    pub min_bond: TokenAmount,
+    /// The duration that unbonded tokens remain slashable before they can be
+    /// claimed
+    pub unbonding_period: Duration,
+}
+
+/// Returned by [`KeeperQueryMsg::KeeperBond`] and contains the bond of a
+/// keeper.
+#[cw_serde]
+pub struct KeeperBondResponse {
+    /// The amount currently bonded by the keeper
+    pub bonded: Uint128,
+    /// The amount that is currently unbonding
+    pub unbonding: Uint128,
+    /// When the unbonding amount can be claimed, or `None` if nothing is
+    /// unbonding
+    pub claimable_at: Option<Expiration>,
+}
+
+/// Returned by [`KeeperQueryMsg::SimulateJob`] and contains the predicted
+/// outcome of executing a job.
+#[cw_serde]
+pub struct SimulateJobResponse {
+    /// Whether executing the job would currently succeed
+    pub success: bool,
+    /// The reward the keeper would receive for executing the job
+    pub reward: TokenAmount,
+    /// The reason the job would be skipped or fail, if `success` is false
+    pub skip_reason: Option<JobSkipReason>,
+}
+
+/// Machine-readable reasons why a job can not currently be executed.
+#[cw_serde]
+pub enum JobSkipReason {
+    /// The job has nothing to do, e.g. there are no rewards to harvest
+    NotReady,
+    /// The job was executed recently and is on cooldown, see
+    /// [`KeeperQueryMsg::NextExecution`]
+    Cooldown,
+    /// The keeper is not allowed to execute the job, e.g. because it is not
+    /// whitelisted
+    Unauthorized,
+    /// The keeper is blacklisted
+    Blacklisted,
+    /// The bond of the keeper is below the [`BondRequirement`]
+    InsufficientBond,
+    /// The vault is paused
+    Paused,
+    /// Executing the job would fail for another reason
+    Other(String),
 }
 
 /// Additional ExecuteMsg variants for vaults that enable the Keeper extension.
@@ -25,16 +148,78 @@ pub enum KeeperExecuteMsg {
         /// The address of the keeper to whitelist
         keeper: String,
     },
-    /// Callable by vault admin to remove a keeper from the whitelist of a job
+    /// Callable by vault admin to remove a keeper from the whitelist of a job.
+    /// See [`KeeperExecuteMsg::Blacklist`] for excluding a keeper from all
+    /// jobs.
     BlacklistKeeper {
         /// The ID of the job to blacklist the keeper for
         job_id: u64,
         /// The address of the keeper to blacklist
         keeper: String,
     },
+    /// Callable by vault admin to add a keeper to the global whitelist,
+    /// allowing it to execute all jobs that require a whitelisted keeper
+    AddKeeper {
+        /// The address of the keeper to add
+        keeper: String,
+    },
+    /// Callable by vault admin to remove a keeper from the global whitelist
+    RemoveKeeper {
+        /// The address of the keeper to remove
+        keeper: String,
+    },
+    /// Callable by vault admin to exclude a keeper from executing any job,
+    /// including jobs that do not require a whitelisted keeper. Emits an
+    /// event with type `KEEPER_BLACKLISTED_EVENT_TYPE` with an attribute with
+    /// key `KEEPER_ATTR_KEY`.
+    Blacklist {
+        /// The address of the keeper to blacklist
+        keeper: String,
+    },
+    /// Callable by vault admin to remove a keeper from the blacklist. Emits an
+    /// event with type `KEEPER_UNBLACKLISTED_EVENT_TYPE` with an attribute
+    /// with key `KEEPER_ATTR_KEY`.
+    RemoveFromBlacklist {
+        /// The address of the keeper to remove from the blacklist
+        keeper: String,
+    },
+    /// Bond tokens as a keeper. The tokens must be sent in the funds field,
+    /// or via a cw20 `Send` if the bond token is a cw20 token. Vaults that do
+    /// not require a bond must return an error.
+    Bond {},
+    /// Start unbonding `amount` of the bonded tokens of the caller. Unbonding
+    /// tokens remain slashable until the end of the unbonding period. Keepers
+    /// whose bond falls below the minimum can no longer execute jobs.
+    Unbond {
+        /// The amount of bonded tokens to unbond
+        amount: Uint128,
+    },
+    /// Claim the tokens of the caller that have finished unbonding
+    ClaimUnbonded {},
+    /// Callable by vault admin to slash part of the bonded and unbonding
+    /// tokens of a keeper for misbehavior. Emits an event with type
+    /// `KEEPER_SLASHED_EVENT_TYPE` with attributes with keys
+    /// `KEEPER_ATTR_KEY`, `KEEPER_SLASH_AMOUNT_ATTR_KEY` and
+    /// `KEEPER_SLASH_REASON_ATTR_KEY`.
+    Slash {
+        /// The address of the keeper to slash
+        keeper: String,
+        /// The amount to slash
+        amount: Uint128,
+        /// A description of the misbehavior
+        reason: String,
+    },
     /// Execute a keeper job. Should only be able to be called if
     /// [`KeeperQueryMsg::KeeperJobReady`] returns true, and only by whitelisted
     /// keepers if the whitelist bool on the KeeperJob is set to true.
+    /// Emits an event with type `KEEPER_EXECUTE_EVENT_TYPE` with attributes
+    /// with keys `KEEPER_JOB_ID_ATTR_KEY`, `KEEPER_ATTR_KEY`,
+    /// `KEEPER_REWARD_ATTR_KEY` and `KEEPER_OUTCOME_ATTR_KEY`, see
+    /// [`keeper_execute_event`]. If a reward is paid, also emits an event with
+    /// type
+    /// `KEEPER_REWARD_PAID_EVENT_TYPE` with attributes with keys
+    /// `KEEPER_JOB_ID_ATTR_KEY`, `KEEPER_ATTR_KEY`, `KEEPER_REWARD_ATTR_KEY`
+    /// and `KEEPER_GAS_REFUND_ATTR_KEY`.
     ExecuteJob {
         /// The ID of the job to execute
         job_id: u64,
@@ -62,16 +247,254 @@ pub enum KeeperQueryMsg {
     /// Returns [`Vec<KeeperJob>`]
     #[returns(Vec<KeeperJob>)]
     KeeperJobs {},
+    /// Returns [`Vec<KeeperJob>`] containing the jobs that are ready to be
+    /// executed, sorted by urgency with the most urgent job first. Vaults
+    /// should order jobs by their `priority` and how long they have been
+    /// ready, e.g. so that overdue harvests come first.
+    #[returns(Vec<KeeperJob>)]
+    KeeperJobsByUrgency {
+        /// Max amount of results to return
+        limit: Option<u32>,
+    },
     /// Returns [`Vec<Addr>`]
     #[returns(Vec<Addr>)]
     WhitelistedKeepers {
         /// The ID of the job to get the whitelisted keepers for
         job_id: u64,
     },
+    /// Returns [`Vec<Addr>`] containing the keepers on the global whitelist,
+    /// added through [`KeeperExecuteMsg::AddKeeper`]
+    #[returns(Vec<Addr>)]
+    Keepers {},
+    /// Returns [`Vec<Addr>`] containing the blacklisted keepers, added
+    /// through [`KeeperExecuteMsg::Blacklist`]
+    #[returns(Vec<Addr>)]
+    BlacklistedKeepers {
+        /// Return results only after this address
+        start_after: Option<String>,
+        /// Max amount of results to return
+        limit: Option<u32>,
+    },
+    /// Returns bool, whether the keeper is blacklisted or not
+    #[returns(bool)]
+    IsBlacklisted {
+        /// The address of the keeper
+        keeper: String,
+    },
     /// Returns bool, whether the keeper job can be executed or not
     #[returns(bool)]
     KeeperJobReady {
         /// The ID of the job to check whether it is ready to be executed
         job_id: u64,
     },
+    /// Returns [`Expiration`] containing the earliest block height or time at
+    /// which the job can be executed again, e.g. after a cooldown. Jobs that
+    /// are ready to be executed should return an expiration that has already
+    /// expired, and jobs that will not become executable again should return
+    /// `Expiration::Never {}`.
+    #[returns(Expiration)]
+    NextExecution {
+        /// The ID of the job to get the next execution time for
+        job_id: u64,
+    },
+    /// Returns [`TokenAmount`] containing the reward that a keeper would
+    /// receive for executing the job in the same block, and the token it is
+    /// paid in, including any gas refund. Jobs without a reward should return
+    /// an amount of zero.
+    #[returns(TokenAmount)]
+    EstimateReward {
+        /// The ID of the job to estimate the reward for
+        job_id: u64,
+    },
+    /// Returns [`Option<GasRefundConfig>`] containing the parameters used to
+    /// refund the gas spent by keepers, or `None` if the vault does not
+    /// refund gas
+    #[returns(Option<GasRefundConfig>)]
+    GasRefundConfig {},
+    /// Returns [`SimulateJobResponse`] containing whether executing the job in
+    /// the same block would succeed, the expected reward, and the reason the
+    /// job would be skipped otherwise
+    #[returns(SimulateJobResponse)]
+    SimulateJob {
+        /// The ID of the job to simulate
+        job_id: u64,
+        /// The address of the keeper that would execute the job. If not set,
+        /// keeper specific checks such as the whitelist and bond are skipped.
+        keeper: Option<String>,
+    },
+    /// Returns [`Option<BondRequirement>`] containing the bond that keepers
+    /// must post, or `None` if the vault does not require keepers to bond
+    #[returns(Option<BondRequirement>)]
+    BondRequirement {},
+    /// Returns [`KeeperBondResponse`] containing the bond of the given keeper
+    #[returns(KeeperBondResponse)]
+    KeeperBond {
+        /// The address of the keeper
+        keeper: String,
+    },
+}
+
+/// The outcome of a call to [`KeeperExecuteMsg::ExecuteJob`], as reported in
+/// the "keeper execute" event.
+#[cw_serde]
+#[derive(Copy, Eq)]
+pub enum JobOutcome {
+    /// The job was executed in full.
+    Success,
+    /// Only part of the work of the job was done, e.g. because of a limit on
+    /// the work done per execution.
+    Partial,
+    /// The job was skipped without doing any work, e.g. because there was
+    /// nothing to do.
+    Skipped,
+}
+
+impl JobOutcome {
+    /// Returns the attribute value for this outcome.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            JobOutcome::Success => "success",
+            JobOutcome::Partial => "partial",
+            JobOutcome::Skipped => "skipped",
+        }
+    }
+}
+
+/// Creates the standardized "keeper execute" event that vaults should emit on
+/// every call to [`KeeperExecuteMsg::ExecuteJob`], so that keeper income can
+/// be attributed across vaults.
+pub fn keeper_execute_event(
+    job_id: u64,
+    keeper: &Addr,
+    reward: &TokenAmount,
+    outcome: JobOutcome,
+) -> Event {
+    Event::new(KEEPER_EXECUTE_EVENT_TYPE)
+        .add_attribute(KEEPER_JOB_ID_ATTR_KEY, job_id.to_string())
+        .add_attribute(KEEPER_ATTR_KEY, keeper.to_string())
+        .add_attribute(KEEPER_REWARD_ATTR_KEY, reward.to_string())
+        .add_attribute(KEEPER_OUTCOME_ATTR_KEY, outcome.as_str())
+}
+
+/// Creates the standardized "keeper reward paid" event that vaults should emit
+/// when a reward is paid for executing a job. `gas_refund` is the part of the
+/// `reward` that refunds gas, see [`GasRefundConfig`].
+pub fn keeper_reward_paid_event(
+    job_id: u64,
+    keeper: &Addr,
+    reward: &TokenAmount,
+    gas_refund: Uint128,
+) -> Event {
+    Event::new(KEEPER_REWARD_PAID_EVENT_TYPE)
+        .add_attribute(KEEPER_JOB_ID_ATTR_KEY, job_id.to_string())
+        .add_attribute(KEEPER_ATTR_KEY, keeper.to_string())
+        .add_attribute(KEEPER_REWARD_ATTR_KEY, reward.to_string())
+        .add_attribute(KEEPER_GAS_REFUND_ATTR_KEY, gas_refund)
+}
+
+/// A helper struct to interact with the keeper extension of a vault contract,
+/// building the nested `VaultExtension` messages for keeper bots. Like
+/// [`crate::VaultContract`], it is generic over the extension enums of the
+/// vault, which must be convertible from the keeper messages.
+#[cw_serde]
+pub struct KeeperClient<E = ExtensionExecuteMsg, Q = ExtensionQueryMsg> {
+    /// The address of the vault contract.
+    pub addr: Addr,
+    /// The extension enum for ExecuteMsg variants.
+    execute_msg_extension: PhantomData<E>,
+    /// The extension enum for QueryMsg variants.
+    query_msg_extension: PhantomData<Q>,
+}
+
+impl<E, Q> KeeperClient<E, Q>
+where
+    E: Serialize + From<KeeperExecuteMsg>,
+    Q: Serialize + JsonSchema + From<KeeperQueryMsg>,
+{
+    /// Create a new KeeperClient instance.
+    pub fn new(addr: &Addr) -> Self {
+        Self {
+            addr: addr.clone(),
+            execute_msg_extension: PhantomData,
+            query_msg_extension: PhantomData,
+        }
+    }
+
+    /// Returns a CosmosMsg to execute the job with the given ID.
+    pub fn execute_job(&self, job_id: u64) -> StdResult<CosmosMsg> {
+        Ok(WasmMsg::Execute {
+            contract_addr: self.addr.to_string(),
+            msg: to_json_binary(&VaultStandardExecuteMsg::<E>::VaultExtension(
+                KeeperExecuteMsg::ExecuteJob { job_id }.into(),
+            ))?,
+            funds: vec![],
+        }
+        .into())
+    }
+
+    /// Queries the vault for all keeper jobs
+    pub fn query_jobs(&self, querier: &QuerierWrapper) -> StdResult<Vec<KeeperJob>> {
+        self.query(querier, KeeperQueryMsg::KeeperJobs {})
+    }
+
+    /// Queries the vault for the ready jobs, sorted by urgency
+    pub fn query_jobs_by_urgency(
+        &self,
+        querier: &QuerierWrapper,
+        limit: Option<u32>,
+    ) -> StdResult<Vec<KeeperJob>> {
+        self.query(querier, KeeperQueryMsg::KeeperJobsByUrgency { limit })
+    }
+
+    /// Queries the vault for whether the job can be executed
+    pub fn query_job_ready(&self, querier: &QuerierWrapper, job_id: u64) -> StdResult<bool> {
+        self.query(querier, KeeperQueryMsg::KeeperJobReady { job_id })
+    }
+
+    /// Queries the vault for the reward of executing the job
+    pub fn estimate_reward(&self, querier: &QuerierWrapper, job_id: u64) -> StdResult<TokenAmount> {
+        self.query(querier, KeeperQueryMsg::EstimateReward { job_id })
+    }
+
+    /// Queries the vault for a simulation of executing the job
+    pub fn simulate_job(
+        &self,
+        querier: &QuerierWrapper,
+        job_id: u64,
+        keeper: Option<String>,
+    ) -> StdResult<SimulateJobResponse> {
+        self.query(querier, KeeperQueryMsg::SimulateJob { job_id, keeper })
+    }
+
+    /// Queries the vault for when the job can be executed again
+    pub fn query_next_execution(
+        &self,
+        querier: &QuerierWrapper,
+        job_id: u64,
+    ) -> StdResult<Expiration> {
+        self.query(querier, KeeperQueryMsg::NextExecution { job_id })
+    }
+
+    fn query<T: DeserializeOwned>(
+        &self,
+        querier: &QuerierWrapper,
+        msg: KeeperQueryMsg,
+    ) -> StdResult<T> {
+        querier.query_wasm_smart(
+            &self.addr,
+            &VaultStandardQueryMsg::<Q>::VaultExtension(msg.into()),
+        )
+    }
+}
+
+impl From<KeeperExecuteMsg> for ExtensionExecuteMsg {
+    fn from(msg: KeeperExecuteMsg) -> Self {
+        ExtensionExecuteMsg::Keeper(msg)
+    }
+}
+
+impl From<KeeperQueryMsg> for ExtensionQueryMsg {
+    fn from(msg: KeeperQueryMsg) -> Self {
+        ExtensionQueryMsg::Keeper(msg)
+    }
 }
@@ -0,0 +1,109 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{to_json_binary, Coin, CosmosMsg, Decimal, StdResult, Uint128, WasmMsg};
+
+use crate::{ExtensionExecuteMsg, VaultStandardExecuteMsg};
+
+/// Additional ExecuteMsg variants for vaults that enable the Leverage
+/// extension.
+#[cw_serde]
+pub enum LeverageExecuteMsg {
+    /// Borrow base tokens against the vault tokens of the caller used as
+    /// collateral. Vault tokens sent in the funds field are added to the
+    /// collateral of the caller before borrowing. Vaults must return an error
+    /// if the loan to value of the position after borrowing would exceed
+    /// `LeverageConfig::max_ltv`.
+    Borrow {
+        /// The amount of base tokens to borrow.
+        amount: Uint128,
+        /// An optional field containing which address should receive the
+        /// borrowed base tokens. If not set, the caller address will be used
+        /// instead.
+        recipient: Option<String>,
+    },
+
+    /// Repay borrowed base tokens. The base tokens must be sent in the funds
+    /// field. Any amount exceeding the debt is refunded.
+    Repay {
+        /// An optional field containing the owner of the position to repay. If
+        /// not set, the caller address will be used instead.
+        owner: Option<String>,
+    },
+
+    /// Withdraw vault tokens from the collateral of the caller. Vaults must
+    /// return an error if the loan to value of the position after withdrawing
+    /// would exceed `LeverageConfig::max_ltv`.
+    WithdrawCollateral {
+        /// The amount of vault tokens to withdraw.
+        amount: Uint128,
+    },
+
+    /// Liquidate a position whose loan to value exceeds
+    /// `LeverageConfig::liquidation_threshold`, repaying its debt with the base
+    /// tokens sent in the funds field in exchange for its collateral plus the
+    /// liquidation bonus.
+    Liquidate {
+        /// The owner of the position to liquidate.
+        owner: String,
+    },
+}
+
+impl LeverageExecuteMsg {
+    /// Convert a [`LeverageExecuteMsg`] into a [`CosmosMsg`].
+    pub fn into_cosmos_msg(self, contract_addr: String, funds: Vec<Coin>) -> StdResult<CosmosMsg> {
+        Ok(WasmMsg::Execute {
+            contract_addr,
+            msg: to_json_binary(&VaultStandardExecuteMsg::VaultExtension(
+                ExtensionExecuteMsg::Leverage(self),
+            ))?,
+            funds,
+        }
+        .into())
+    }
+}
+
+/// Additional QueryMsg variants for vaults that enable the Leverage extension.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum LeverageQueryMsg {
+    /// Returns `LeveragePosition` of the given owner.
+    #[returns(LeveragePosition)]
+    Position {
+        /// The owner of the position.
+        owner: String,
+    },
+
+    /// Returns `LeverageConfig` containing the borrowing parameters of the
+    /// vault.
+    #[returns(LeverageConfig)]
+    LeverageConfig {},
+}
+
+/// The borrowing parameters of the vault. All ratios are fractions of the
+/// value of the collateral.
+#[cw_serde]
+pub struct LeverageConfig {
+    /// The maximum loan to value at which base tokens can be borrowed.
+    pub max_ltv: Decimal,
+    /// The loan to value above which a position can be liquidated.
+    pub liquidation_threshold: Decimal,
+    /// The bonus received by liquidators, as a fraction of the repaid debt.
+    pub liquidation_bonus: Decimal,
+    /// The current annual borrow rate.
+    pub borrow_rate: Decimal,
+}
+
+/// Returned by `LeverageQueryMsg::Position` and contains the borrow position
+/// of an owner.
+#[cw_serde]
+pub struct LeveragePosition {
+    /// The amount of vault tokens used as collateral.
+    pub collateral: Uint128,
+    /// The value of the collateral in base tokens.
+    pub collateral_value: Uint128,
+    /// The amount of base tokens owed, including accrued interest.
+    pub debt: Uint128,
+    /// The current loan to value of the position.
+    pub ltv: Decimal,
+    /// Whether the position can currently be liquidated.
+    pub liquidatable: bool,
+}
@@ -1,5 +1,9 @@
+use std::cmp::Ordering;
+
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{to_json_binary, Addr, Coin, CosmosMsg, StdResult, Uint128, WasmMsg};
+use cosmwasm_std::{
+    to_json_binary, Addr, BlockInfo, Coin, CosmosMsg, Decimal, Event, StdResult, Uint128, WasmMsg,
+};
 use cw_utils::{Duration, Expiration};
 
 use crate::{ExtensionExecuteMsg, VaultStandardExecuteMsg};
@@ -10,6 +14,46 @@ pub const UNLOCKING_POSITION_CREATED_EVENT_TYPE: &str = "unlocking_position_crea
 /// that is emitted on call to `Unlock`.
 pub const UNLOCKING_POSITION_ATTR_KEY: &str = "lockup_id";
 
+/// Type for the event emitted on call to `TransferLockup`.
+pub const LOCKUP_TRANSFERRED_EVENT_TYPE: &str = "lockup_transferred";
+/// Key for the attribute containing the previous owner of the position in the
+/// "lockup transferred" event. The lockup id is contained in an attribute with
+/// key `UNLOCKING_POSITION_ATTR_KEY`.
+pub const LOCKUP_FROM_ATTR_KEY: &str = "from";
+/// Key for the attribute containing the new owner of the position in the
+/// "lockup transferred" event.
+pub const LOCKUP_TO_ATTR_KEY: &str = "to";
+
+/// Type for the event emitted when base tokens are locked, e.g. on call to
+/// `DepositAndLock`.
+pub const LOCK_EVENT_TYPE: &str = "lock";
+/// Type for the event emitted when a position starts unlocking, e.g. on call
+/// to `Unlock` or `PartialUnlock`.
+pub const BEGIN_UNLOCK_EVENT_TYPE: &str = "begin_unlock";
+/// Type for the event emitted when a matured position is claimed, e.g. on call
+/// to `WithdrawUnlocked`.
+pub const UNLOCK_CLAIMED_EVENT_TYPE: &str = "unlock_claimed";
+/// Type for the event emitted when a position is unlocked before its release
+/// time by a privileged address, e.g. on call to `ForceUnlock`.
+pub const FORCE_UNLOCK_EVENT_TYPE: &str = "force_unlock";
+/// Key for the attribute containing the owner of the position in the lockup
+/// lifecycle events. The lockup id is contained in an attribute with key
+/// `UNLOCKING_POSITION_ATTR_KEY`.
+pub const LOCKUP_OWNER_ATTR_KEY: &str = "owner";
+/// Key for the attribute containing the amount of base tokens in the lockup
+/// lifecycle events.
+pub const LOCKUP_AMOUNT_ATTR_KEY: &str = "amount";
+/// Key for the attribute containing the release time of the position in the
+/// lockup lifecycle events.
+pub const LOCKUP_RELEASE_AT_ATTR_KEY: &str = "release_at";
+
+/// The default number of positions returned by
+/// `LockupQueryMsg::UnlockingPositions` if no limit is given.
+pub const DEFAULT_UNLOCKING_POSITIONS_LIMIT: u32 = 10;
+/// The maximum number of positions returned by
+/// `LockupQueryMsg::UnlockingPositions`.
+pub const MAX_UNLOCKING_POSITIONS_LIMIT: u32 = 100;
+
 /// Additional ExecuteMsg variants for vaults that enable the Lockup extension.
 #[cw_serde]
 pub enum LockupExecuteMsg {
@@ -30,6 +74,10 @@ pub enum LockupExecuteMsg {
             of vault tokens should instead be read from the actual amount of sent vault tokens."
         )]
         amount: Uint128,
+        /// The ID of the lockup tier to unlock with, see
+        /// `LockupQueryMsg::LockupTiers`. If not set, the duration returned by
+        /// `LockupQueryMsg::LockupDuration` will be used.
+        tier: Option<u32>,
     },
 
     /// EmergencyUnlock is called to initiate unlocking a locked position held
@@ -43,6 +91,113 @@ pub enum LockupExecuteMsg {
         amount: Uint128,
     },
 
+    /// Deposit base tokens into the vault and immediately start unlocking the
+    /// resulting vault tokens, in a single message. This is equivalent to
+    /// calling `Deposit` followed by `Unlock`, but avoids the need for two
+    /// transactions and any front-running between them.
+    /// Native base tokens are passed in the funds parameter.
+    /// Emits the same event as `Unlock`, with type
+    /// `UNLOCKING_POSITION_CREATED_EVENT_TYPE` and an attribute with key
+    /// `UNLOCKING_POSITION_ATTR_KEY` containing the u64 lockup_id.
+    DepositAndLock {
+        /// The amount of base tokens to deposit.
+        amount: Uint128,
+        /// The duration of the lockup. If not set, the duration returned by
+        /// `LockupQueryMsg::LockupDuration` will be used. Vaults that only
+        /// support a single lockup duration should return an error if any
+        /// other duration is passed in, as should vaults that do not support
+        /// the kind of the given duration (height or time).
+        duration: Option<Duration>,
+        /// The ID of the lockup tier to lock with, see
+        /// `LockupQueryMsg::LockupTiers`. Vaults must return an error if both
+        /// `duration` and `tier` are set.
+        tier: Option<u32>,
+        /// The optional owner of the created unlocking position. If not set,
+        /// the caller address will be used instead.
+        recipient: Option<String>,
+    },
+
+    /// Start unlocking only part of the locked position with the given ID,
+    /// leaving the rest of the position locked. The IDs of the locked
+    /// positions of an owner are returned by `LockupQueryMsg::LockedPositions`.
+    /// Emits an event with type `UNLOCKING_POSITION_CREATED_EVENT_TYPE` with
+    /// an attribute with key `UNLOCKING_POSITION_ATTR_KEY` containing the u64
+    /// lockup_id of the newly created unlocking position. The data field of
+    /// the response must be set to a JSON encoded [`PartialUnlockResponse`].
+    PartialUnlock {
+        /// The ID of the locked position to unlock from.
+        lockup_id: u64,
+        /// The amount of base tokens to start unlocking. Must be less than the
+        /// amount in the position. Use `Unlock` to unlock an entire position.
+        amount: Uint128,
+    },
+
+    /// Immediately withdraw an unlocking position that has not yet finished
+    /// unlocking, bypassing the remaining lockup duration in exchange for a
+    /// penalty. The penalty is determined by the schedule returned by
+    /// `LockupQueryMsg::PenaltySchedule`. Vaults that do not support early
+    /// unlocks must return an error.
+    ///
+    /// Unlike `EmergencyUnlock`, which only starts unlocking vault tokens,
+    /// this returns the base tokens of the position right away.
+    UnlockEarly {
+        /// The ID of the unlocking position to withdraw.
+        lockup_id: u64,
+        /// An optional field containing which address should receive the
+        /// withdrawn base tokens. If not set, the caller address will be
+        /// used instead.
+        recipient: Option<String>,
+    },
+
+    /// Transfer ownership of a locked or unlocking position to another
+    /// address, e.g. into a credit account or a new wallet. Can only be called
+    /// by the current owner of the position.
+    /// Emits an event with type `LOCKUP_TRANSFERRED_EVENT_TYPE` with
+    /// attributes with keys `UNLOCKING_POSITION_ATTR_KEY`,
+    /// `LOCKUP_FROM_ATTR_KEY` and `LOCKUP_TO_ATTR_KEY`. Afterwards the
+    /// position is returned by `LockupQueryMsg::UnlockingPositions` for the
+    /// new owner.
+    TransferLockup {
+        /// The ID of the position to transfer.
+        lockup_id: u64,
+        /// The address of the new owner of the position.
+        to: String,
+    },
+
+    /// Extend the duration of an existing lockup position, e.g. to maintain a
+    /// boost, without having to withdraw and deposit again. Can only be called
+    /// by the owner of the position. The release time of the position is
+    /// pushed back by `extend_by`, i.e. the new release time is `extend_by`
+    /// after the current release time of the position. Vaults must return an
+    /// error if the kind of `extend_by` (height or time) does not match the
+    /// kind of the release time, if the new release time would be more than
+    /// `LockupConfigResponse::max_duration` after the current block or if
+    /// `LockupConfigResponse::extendable` is false.
+    ExtendLockDuration {
+        /// The ID of the position to extend.
+        lockup_id: u64,
+        /// The duration to add to the current release time of the position.
+        extend_by: Duration,
+    },
+
+    /// Enable or disable automatic relocking of a lockup position when it has
+    /// finished unlocking. Can only be called by the owner of the position.
+    SetAutoRelock {
+        /// The ID of the position.
+        lockup_id: u64,
+        /// Whether the position should be relocked automatically.
+        enabled: bool,
+    },
+
+    /// Relock a position that has finished unlocking and has automatic
+    /// relocking enabled, for the same duration as before. Callable by anyone,
+    /// e.g. by keeper bots discovering positions through
+    /// `LockupQueryMsg::AutoRelockPositions`.
+    Relock {
+        /// The ID of the position to relock.
+        lockup_id: u64,
+    },
+
     /// Withdraw an unlocking position that has finished unlocking.
     WithdrawUnlocked {
         /// An optional field containing which address should receive the
@@ -52,6 +207,35 @@ pub enum LockupExecuteMsg {
         /// The ID of the expired lockup to withdraw from.
         lockup_id: u64,
     },
+
+    /// Withdraw multiple unlocking positions of the caller that have finished
+    /// unlocking, in a single message. If `lockup_ids` is set, only those
+    /// positions are withdrawn and the vault must fail if any of them has not
+    /// finished unlocking. Otherwise all matured positions of the caller are
+    /// withdrawn.
+    WithdrawAllUnlocked {
+        /// The IDs of the expired lockups to withdraw from. If not set, all
+        /// expired lockups of the caller are withdrawn.
+        lockup_ids: Option<Vec<u64>>,
+        /// An optional field containing which address should receive the
+        /// withdrawn base tokens. If not set, the caller address will be
+        /// used instead.
+        recipient: Option<String>,
+    },
+
+    /// Reclaim the base tokens of matured positions that were not withdrawn
+    /// before their claim deadline, see `LockupQueryMsg::ClaimWindow`. Can only
+    /// be called by the admin of the vault. Vaults must return an error if any
+    /// of the positions has not passed its claim deadline, or if the vault
+    /// does not have a claim window.
+    ReclaimForfeited {
+        /// The IDs of the forfeited positions to reclaim.
+        lockup_ids: Vec<u64>,
+        /// An optional field containing which address should receive the
+        /// reclaimed base tokens. If not set, the caller address will be used
+        /// instead.
+        recipient: Option<String>,
+    },
 }
 
 impl LockupExecuteMsg {
@@ -72,12 +256,18 @@ impl LockupExecuteMsg {
 #[cw_serde]
 #[derive(QueryResponses)]
 pub enum LockupQueryMsg {
-    /// Returns a `Vec<UnlockingPosition>` containing all the currently
-    /// unclaimed lockup positions for the `owner`.
+    /// Returns a `Vec<UnlockingPosition>` containing the currently unclaimed
+    /// lockup positions, ordered by lockup_id. If `owner` is set, only the
+    /// positions of `owner` are returned, otherwise the positions of all
+    /// owners are returned, allowing indexers to enumerate the vault.
+    ///
+    /// If `limit` is not set, at most `DEFAULT_UNLOCKING_POSITIONS_LIMIT`
+    /// positions are returned. Vaults must cap `limit` at
+    /// `MAX_UNLOCKING_POSITIONS_LIMIT`.
     #[returns(Vec<UnlockingPosition>)]
     UnlockingPositions {
         /// The address of the owner of the lockup
-        owner: String,
+        owner: Option<String>,
         /// Return results only after this lockup_id
         start_after: Option<u64>,
         /// Max amount of results to return
@@ -92,9 +282,244 @@ pub enum LockupQueryMsg {
         lockup_id: u64,
     },
 
-    /// Returns `cw_utils::Duration` duration of the lockup of the vault.
+    /// Returns a `Vec<UnlockingPosition>` containing the unlocking positions
+    /// with the given IDs, in the same order as `ids`. Must fail if any of the
+    /// positions does not exist.
+    ///
+    /// Allows liquidation bots and credit managers tracking specific positions
+    /// to refresh them in a single query.
+    #[returns(Vec<UnlockingPosition>)]
+    UnlockingPositionsByIds {
+        /// The IDs of the lockups to query
+        ids: Vec<u64>,
+    },
+
+    /// Returns a `Vec<LockedPosition>` containing the positions that are
+    /// locked and have not started unlocking, ordered by lockup_id. If `owner`
+    /// is set, only the positions of `owner` are returned, otherwise the
+    /// positions of all owners are returned. The page size is bounded in the
+    /// same way as for `UnlockingPositions`.
+    #[returns(Vec<LockedPosition>)]
+    LockedPositions {
+        /// The address of the owner of the lockup
+        owner: Option<String>,
+        /// Return results only after this lockup_id
+        start_after: Option<u64>,
+        /// Max amount of results to return
+        limit: Option<u32>,
+    },
+
+    /// Returns `cw_utils::Duration` duration of the lockup of the vault. The
+    /// duration may be either a number of blocks (`Duration::Height`) or a
+    /// number of seconds (`Duration::Time`), and determines the kind of
+    /// `Expiration` of the vault's unlocking positions.
     #[returns(Duration)]
     LockupDuration {},
+
+    /// Returns `cw_utils::Expiration` at which a position would finish
+    /// unlocking if it was locked with the given duration or tier in the same
+    /// transaction, i.e. `cw_utils::Duration::after` applied to the current
+    /// block. Vaults must return an error if the kind of the given duration
+    /// (height or time) is not supported.
+    #[returns(Expiration)]
+    PreviewReleaseAt {
+        /// The duration of the lockup. If not set, the duration returned by
+        /// `LockupQueryMsg::LockupDuration` will be used.
+        duration: Option<Duration>,
+        /// The ID of the lockup tier to preview locking with.
+        tier: Option<u32>,
+    },
+
+    /// Returns `Uint128` amount of base tokens that the unlocking position
+    /// created by `LockupExecuteMsg::DepositAndLock` would contain, if called
+    /// with the same arguments in the same transaction.
+    #[returns(Uint128)]
+    PreviewDepositAndLock {
+        /// The amount of base tokens to preview depositing.
+        amount: Uint128,
+        /// The duration of the lockup. If not set, the duration returned by
+        /// `LockupQueryMsg::LockupDuration` will be used.
+        duration: Option<Duration>,
+        /// The ID of the lockup tier to preview locking with.
+        tier: Option<u32>,
+    },
+
+    /// Returns a `Vec<LockupTier>` containing the lockup tiers supported by
+    /// the vault, with their durations and reward boosts. Vaults that do not
+    /// support tiers should return an empty list.
+    #[returns(Vec<LockupTier>)]
+    LockupTiers {},
+
+    /// Returns the `PenaltySchedule` applied to early unlocks through
+    /// `LockupExecuteMsg::UnlockEarly`, or `None` if the vault does not
+    /// support early unlocks.
+    #[returns(Option<PenaltySchedule>)]
+    PenaltySchedule {},
+
+    /// Returns `Uint128` amount of base tokens that would be returned by
+    /// calling `LockupExecuteMsg::UnlockEarly` for the given position in the
+    /// same transaction, after the penalty is charged.
+    #[returns(Uint128)]
+    PreviewUnlockEarly {
+        /// The ID of the unlocking position.
+        lockup_id: u64,
+    },
+
+    /// Returns `LockupConfigResponse` describing the lockup parameters of the
+    /// vault, so that integrators don't need to hardcode them per vault.
+    #[returns(LockupConfigResponse)]
+    LockupConfig {},
+
+    /// Returns bool, whether automatic relocking is enabled for the position.
+    #[returns(bool)]
+    AutoRelockEnabled {
+        /// The ID of the position.
+        lockup_id: u64,
+    },
+
+    /// Returns a `Vec<UnlockingPosition>` containing the positions with
+    /// automatic relocking enabled, ordered by lockup_id. If `matured_only` is
+    /// true, only positions that have finished unlocking and can be relocked
+    /// with `LockupExecuteMsg::Relock` are returned.
+    #[returns(Vec<UnlockingPosition>)]
+    AutoRelockPositions {
+        /// Whether to only return positions that have finished unlocking.
+        matured_only: bool,
+        /// Return results only after this lockup_id
+        start_after: Option<u64>,
+        /// Max amount of results to return
+        limit: Option<u32>,
+    },
+
+    /// Returns a `Vec<UnlockingPosition>` containing the positions of all
+    /// owners that have finished unlocking but have not been withdrawn,
+    /// ordered by lockup_id. Allows keeper bots to discover claimable
+    /// positions without indexing the vault themselves. The page size is
+    /// bounded in the same way as for `UnlockingPositions`.
+    #[returns(Vec<UnlockingPosition>)]
+    MaturedUnlocks {
+        /// Return results only after this lockup_id
+        start_after: Option<u64>,
+        /// Max amount of results to return
+        limit: Option<u32>,
+    },
+
+    /// Returns `Option<cw_utils::Duration>` for which matured positions can
+    /// be withdrawn after they have finished unlocking. After this window the
+    /// position is forfeited and may be reclaimed through
+    /// `LockupExecuteMsg::ReclaimForfeited`. Returns `None` if matured
+    /// positions never expire.
+    #[returns(Option<Duration>)]
+    ClaimWindow {},
+
+    /// Returns `Option<cw_utils::Expiration>` after which the position with the
+    /// given ID can no longer be withdrawn and is forfeited, or `None` if the
+    /// vault does not have a claim window.
+    #[returns(Option<Expiration>)]
+    ClaimDeadline {
+        /// The ID of the unlocking position.
+        lockup_id: u64,
+    },
+
+    /// Returns `Decimal` reward boost multiplier of the given owner, derived
+    /// from the amounts and durations of their lockup positions. E.g. `1.5`
+    /// for a 50% boost. Owners without lockup positions have a boost of `1`.
+    /// Allows external reward distributors and gauges to consume lockup data
+    /// without vault specific adapters.
+    #[returns(Decimal)]
+    Boost {
+        /// The address of the owner.
+        owner: String,
+    },
+
+    /// Returns `TotalLockupStateResponse` containing the aggregate amounts of
+    /// base tokens locked and unlocking in the vault across all owners.
+    #[returns(TotalLockupStateResponse)]
+    TotalLockupState {},
+}
+
+/// Returned by `LockupQueryMsg::LockupConfig` and contains the lockup
+/// parameters of the vault.
+#[cw_serde]
+pub struct LockupConfigResponse {
+    /// The minimum duration of a lockup.
+    pub min_duration: Duration,
+    /// The maximum duration of a lockup.
+    pub max_duration: Duration,
+    /// Whether the duration of existing lockups can be extended.
+    pub extendable: bool,
+    /// The penalty schedule applied to early unlocks, or `None` if early
+    /// unlocks are not supported.
+    pub penalty_schedule: Option<PenaltySchedule>,
+    /// Whether the vault enables the ForceUnlock extension.
+    pub force_unlock_enabled: bool,
+}
+
+/// Returned by `LockupQueryMsg::TotalLockupState` and contains the aggregate
+/// lockup state of the vault.
+#[cw_serde]
+pub struct TotalLockupStateResponse {
+    /// The total amount of base tokens that are locked and have not started
+    /// unlocking.
+    pub total_locked: Uint128,
+    /// The total amount of base tokens in positions that are currently
+    /// unlocking, including matured positions that have not been withdrawn.
+    pub total_unlocking: Uint128,
+    /// The earliest release time of the positions that are currently
+    /// unlocking and not yet mature, or `None` if there are no such positions.
+    pub next_release_at: Option<Expiration>,
+}
+
+/// The penalty charged for unlocking a position before it has finished
+/// unlocking, as a fraction of the base tokens in the position.
+#[cw_serde]
+pub enum PenaltySchedule {
+    /// A fixed penalty, regardless of the remaining lockup duration.
+    Fixed(Decimal),
+    /// A penalty decreasing linearly from `max` at the start of the lockup to
+    /// zero when the position has finished unlocking.
+    Linear {
+        /// The penalty charged at the start of the lockup.
+        max: Decimal,
+    },
+}
+
+/// A lockup tier, pairing a lockup duration with the reward boost received by
+/// positions locked for that duration.
+#[cw_serde]
+pub struct LockupTier {
+    /// The ID of the tier.
+    pub id: u32,
+    /// The duration of the lockup.
+    pub duration: Duration,
+    /// The multiplier applied to the rewards of positions in this tier. E.g.
+    /// `1.5` for a 50% boost.
+    pub boost: Decimal,
+}
+
+/// Set as the data of the response to `LockupExecuteMsg::PartialUnlock`,
+/// containing the IDs of the positions resulting from the partial unlock.
+#[cw_serde]
+pub struct PartialUnlockResponse {
+    /// The ID of the newly created unlocking position.
+    pub unlocking_lockup_id: u64,
+    /// The ID of the position containing the amount that remains locked. May
+    /// be equal to the ID of the original position.
+    pub remaining_lockup_id: u64,
+}
+
+/// Info about a position that is locked and has not started unlocking.
+#[cw_serde]
+pub struct LockedPosition {
+    /// The ID of the lockup, to be passed to `LockupExecuteMsg::PartialUnlock`.
+    pub id: u64,
+    /// The address of the owner of the lockup.
+    pub owner: Addr,
+    /// The `cw_utils::Duration` the position takes to unlock once it starts
+    /// unlocking.
+    pub duration: Duration,
+    /// The amount of base tokens that are locked.
+    pub base_token_amount: Uint128,
 }
 
 /// Info about a currenly unlocking position.
@@ -110,3 +535,132 @@ pub struct UnlockingPosition {
     /// The amount of base tokens that are being unlocked.
     pub base_token_amount: Uint128,
 }
+
+/// The stages in the lifecycle of a lockup position for which vaults should
+/// emit a standardized event.
+#[cw_serde]
+#[derive(Copy, Eq)]
+pub enum LockupEventType {
+    /// Base tokens were locked.
+    Lock,
+    /// The position started unlocking.
+    BeginUnlock,
+    /// The matured position was claimed by its owner.
+    UnlockClaimed,
+    /// The position was unlocked before its release time.
+    ForceUnlock,
+}
+
+impl LockupEventType {
+    /// Returns the event type string for this lifecycle stage.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LockupEventType::Lock => LOCK_EVENT_TYPE,
+            LockupEventType::BeginUnlock => BEGIN_UNLOCK_EVENT_TYPE,
+            LockupEventType::UnlockClaimed => UNLOCK_CLAIMED_EVENT_TYPE,
+            LockupEventType::ForceUnlock => FORCE_UNLOCK_EVENT_TYPE,
+        }
+    }
+}
+
+impl UnlockingPosition {
+    /// Returns true if the position has completed unlocking at the given
+    /// block, i.e. its base tokens can be withdrawn.
+    pub fn is_mature(&self, block: &BlockInfo) -> bool {
+        self.release_at.is_expired(block)
+    }
+
+    /// Returns the `Duration` remaining until the position has completed
+    /// unlocking at the given block, in blocks or seconds depending on the
+    /// kind of `release_at`. Returns a zero duration if the position is
+    /// already mature and `None` if it never completes unlocking.
+    pub fn remaining(&self, block: &BlockInfo) -> Option<Duration> {
+        match self.release_at {
+            Expiration::AtHeight(height) => {
+                Some(Duration::Height(height.saturating_sub(block.height)))
+            }
+            Expiration::AtTime(time) => Some(Duration::Time(
+                time.seconds().saturating_sub(block.time.seconds()),
+            )),
+            Expiration::Never {} => None,
+        }
+    }
+
+    /// Compares two positions by their release time, earliest first. Release
+    /// times of different kinds are not comparable, so positions are ordered
+    /// by the kind of their release time first (heights before times, and
+    /// positions that never complete unlocking last), then by the release
+    /// time and finally by their id.
+    pub fn cmp_release(&self, other: &Self) -> Ordering {
+        fn key(expiration: &Expiration) -> (u8, u64) {
+            match expiration {
+                Expiration::AtHeight(height) => (0, *height),
+                Expiration::AtTime(time) => (1, time.nanos()),
+                Expiration::Never {} => (2, 0),
+            }
+        }
+
+        key(&self.release_at)
+            .cmp(&key(&other.release_at))
+            .then(self.id.cmp(&other.id))
+    }
+
+    /// Creates the standardized lifecycle event of type `event_type` for this
+    /// position. Vaults should add the returned event to the `Response` of the
+    /// message that caused the lifecycle change so that indexers can track
+    /// positions without knowledge of the vault implementation.
+    pub fn to_event(&self, event_type: LockupEventType) -> Event {
+        Event::new(event_type.as_str())
+            .add_attribute(UNLOCKING_POSITION_ATTR_KEY, self.id.to_string())
+            .add_attribute(LOCKUP_OWNER_ATTR_KEY, self.owner.to_string())
+            .add_attribute(LOCKUP_AMOUNT_ATTR_KEY, self.base_token_amount)
+            .add_attribute(LOCKUP_RELEASE_AT_ATTR_KEY, self.release_at.to_string())
+    }
+}
+
+/// Sorts the given positions by their release time, earliest first. See
+/// [`UnlockingPosition::cmp_release`].
+pub fn sort_by_release(positions: &mut [UnlockingPosition]) {
+    positions.sort_by(UnlockingPosition::cmp_release);
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::Timestamp;
+
+    use super::*;
+
+    fn position(id: u64, release_at: Expiration) -> UnlockingPosition {
+        UnlockingPosition {
+            id,
+            owner: Addr::unchecked("owner"),
+            release_at,
+            base_token_amount: Uint128::new(100),
+        }
+    }
+
+    #[test]
+    fn sort_by_release_with_mixed_expiration_kinds() {
+        let mut positions = vec![
+            position(1, Expiration::Never {}),
+            position(2, Expiration::AtTime(Timestamp::from_seconds(10))),
+            position(3, Expiration::AtHeight(20)),
+            position(4, Expiration::AtTime(Timestamp::from_seconds(5))),
+            position(5, Expiration::AtHeight(10)),
+            position(6, Expiration::AtHeight(10)),
+            position(7, Expiration::Never {}),
+        ];
+        sort_by_release(&mut positions);
+
+        let ids: Vec<u64> = positions.iter().map(|p| p.id).collect();
+        assert_eq!(ids, vec![5, 6, 3, 4, 2, 1, 7]);
+    }
+
+    #[test]
+    fn cmp_release_is_antisymmetric_across_kinds() {
+        let height = position(2, Expiration::AtHeight(u64::MAX));
+        let time = position(1, Expiration::AtTime(Timestamp::from_seconds(0)));
+        assert_eq!(height.cmp_release(&time), Ordering::Less);
+        assert_eq!(time.cmp_release(&height), Ordering::Greater);
+    }
+}
@@ -0,0 +1,70 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{to_json_binary, Addr, Coin, CosmosMsg, StdResult, WasmMsg};
+
+use crate::extensions::lockup::UnlockingPosition;
+use crate::{ExtensionExecuteMsg, VaultStandardExecuteMsg};
+
+/// Additional ExecuteMsg variants for vaults that enable the LockupNft
+/// extension.
+#[cw_serde]
+pub enum LockupNftExecuteMsg {
+    /// Wrap a lockup position into a cw721 token, minted on the contract
+    /// returned by `LockupNftQueryMsg::NftContract`. Can only be called by the
+    /// owner of the position. While wrapped, the position is owned by whoever
+    /// holds the token.
+    WrapLockup {
+        /// The ID of the lockup position to wrap.
+        lockup_id: u64,
+        /// The address that should receive the minted token. If not set, the
+        /// caller address will be used instead.
+        recipient: Option<String>,
+    },
+
+    /// Unwrap a lockup position, burning its cw721 token and making the caller
+    /// the owner of the position. Can only be called by the holder of the
+    /// token.
+    UnwrapLockup {
+        /// The ID of the cw721 token to unwrap.
+        token_id: String,
+    },
+}
+
+impl LockupNftExecuteMsg {
+    /// Convert a [`LockupNftExecuteMsg`] into a [`CosmosMsg`].
+    pub fn into_cosmos_msg(self, contract_addr: String, funds: Vec<Coin>) -> StdResult<CosmosMsg> {
+        Ok(WasmMsg::Execute {
+            contract_addr,
+            msg: to_json_binary(&VaultStandardExecuteMsg::VaultExtension(
+                ExtensionExecuteMsg::LockupNft(self),
+            ))?,
+            funds,
+        }
+        .into())
+    }
+}
+
+/// Additional QueryMsg variants for vaults that enable the LockupNft
+/// extension.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum LockupNftQueryMsg {
+    /// Returns the `Addr` of the cw721 contract on which wrapped lockup
+    /// positions are minted.
+    #[returns(Addr)]
+    NftContract {},
+
+    /// Returns the `UnlockingPosition` wrapped by the given cw721 token.
+    #[returns(UnlockingPosition)]
+    LockupByTokenId {
+        /// The ID of the cw721 token.
+        token_id: String,
+    },
+
+    /// Returns an `Option<String>` containing the ID of the cw721 token
+    /// wrapping the given lockup position, or `None` if it is not wrapped.
+    #[returns(Option<String>)]
+    TokenIdByLockupId {
+        /// The ID of the lockup position.
+        lockup_id: u64,
+    },
+}
@@ -0,0 +1,53 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Decimal, Timestamp, Uint128};
+
+/// Additional QueryMsg variants for vaults that enable the Metrics extension.
+///
+/// All metrics are self-reported by the vault and are not verified by this
+/// standard. Integrators should treat them as informational only.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum MetricsQueryMsg {
+    /// Returns `YieldMetric` containing the self-reported annual percentage
+    /// rate of the vault, without compounding.
+    #[returns(YieldMetric)]
+    Apr {},
+
+    /// Returns `YieldMetric` containing the self-reported annual percentage
+    /// yield of the vault, including compounding.
+    #[returns(YieldMetric)]
+    Apy {},
+
+    /// Returns a `Vec<PerformancePoint>` containing the self-reported
+    /// performance history of the vault between `from` and `to`, ordered by
+    /// time.
+    #[returns(Vec<PerformancePoint>)]
+    PerformanceHistory {
+        /// The start of the period, inclusive.
+        from: Timestamp,
+        /// The end of the period, inclusive.
+        to: Timestamp,
+    },
+}
+
+/// A self-reported yield of the vault.
+#[cw_serde]
+pub struct YieldMetric {
+    /// The yield as a fraction, e.g. `0.05` for 5%.
+    pub rate: Decimal,
+    /// The number of seconds over which the yield was measured.
+    pub window: u64,
+    /// The block time at which the yield was last updated.
+    pub updated_at: Timestamp,
+}
+
+/// A self-reported data point of the performance history of the vault.
+#[cw_serde]
+pub struct PerformancePoint {
+    /// The block time of the data point.
+    pub time: Timestamp,
+    /// The share price, in base tokens per vault token.
+    pub share_price: Decimal,
+    /// The total amount of base tokens managed by the vault.
+    pub total_assets: Uint128,
+}
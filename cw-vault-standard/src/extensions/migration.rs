@@ -0,0 +1,79 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{to_json_binary, Addr, Coin, CosmosMsg, StdResult, Uint128, WasmMsg};
+
+use crate::{ExtensionExecuteMsg, VaultStandardExecuteMsg};
+
+/// Additional ExecuteMsg variants for vaults that enable the Migration
+/// extension.
+#[cw_serde]
+pub enum MigrationExecuteMsg {
+    /// Redeem vault tokens and deposit the withdrawn base tokens into another
+    /// vault in a single transaction. The native vault tokens must be passed
+    /// in the funds field. `to_vault` must be an approved migration target,
+    /// see `MigrationQueryMsg::MigrationTargets`, and must have the same base
+    /// token. Vaults should carry over lockups and waive exit fees where
+    /// possible.
+    MigrateShares {
+        /// The address of the destination vault.
+        to_vault: String,
+        /// The amount of vault tokens to migrate.
+        amount: Uint128,
+        /// The minimum amount of vault tokens of the destination vault that
+        /// must be minted. If fewer would be minted, the vault must fail with
+        /// [`crate::VaultStandardError::MinOutputNotMet`].
+        min_shares_out: Option<Uint128>,
+        /// An optional field containing which address should receive the
+        /// vault tokens of the destination vault. If not set, the caller
+        /// address will be used instead.
+        recipient: Option<String>,
+    },
+
+    /// Approve a vault as migration target. Can only be called by the admin of
+    /// the vault.
+    AddMigrationTarget {
+        /// The address of the destination vault.
+        vault: String,
+    },
+
+    /// Remove a vault from the approved migration targets. Can only be called
+    /// by the admin of the vault.
+    RemoveMigrationTarget {
+        /// The address of the destination vault.
+        vault: String,
+    },
+}
+
+impl MigrationExecuteMsg {
+    /// Convert a [`MigrationExecuteMsg`] into a [`CosmosMsg`].
+    pub fn into_cosmos_msg(self, contract_addr: String, funds: Vec<Coin>) -> StdResult<CosmosMsg> {
+        Ok(WasmMsg::Execute {
+            contract_addr,
+            msg: to_json_binary(&VaultStandardExecuteMsg::VaultExtension(
+                ExtensionExecuteMsg::Migration(self),
+            ))?,
+            funds,
+        }
+        .into())
+    }
+}
+
+/// Additional QueryMsg variants for vaults that enable the Migration extension.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum MigrationQueryMsg {
+    /// Returns a `Vec<Addr>` containing the vaults that vault tokens can be
+    /// migrated to.
+    #[returns(Vec<Addr>)]
+    MigrationTargets {},
+
+    /// Returns `Uint128` amount of vault tokens of `to_vault` that would be
+    /// minted by calling `MigrationExecuteMsg::MigrateShares` with the same
+    /// arguments in the same transaction.
+    #[returns(Uint128)]
+    PreviewMigrateShares {
+        /// The address of the destination vault.
+        to_vault: String,
+        /// The amount of vault tokens to migrate.
+        amount: Uint128,
+    },
+}
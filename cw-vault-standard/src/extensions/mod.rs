@@ -25,6 +25,194 @@ pub mod force_unlock;
 #[cfg_attr(docsrs, doc(cfg(feature = "keeper")))]
 pub mod keeper;
 
+/// The lockup NFT extension can be used together with the `Lockup` extension
+/// to represent lockup positions as cw721 tokens. This makes locked positions
+/// tradable and usable as collateral in NFT aware protocols.
+#[cfg(feature = "lockup-nft")]
+#[cfg_attr(docsrs, doc(cfg(feature = "lockup-nft")))]
+pub mod lockup_nft;
+
+/// The instant unlock extension can be used together with the `Lockup`
+/// extension by vaults that can source exit liquidity for locked positions,
+/// to let users skip the unlock period in exchange for a haircut.
+#[cfg(feature = "instant-unlock")]
+#[cfg_attr(docsrs, doc(cfg(feature = "instant-unlock")))]
+pub mod instant_unlock;
+
+/// The fee extension can be used by vaults that charge fees, to expose their
+/// fee configuration in a uniform way.
+#[cfg(feature = "fee")]
+#[cfg_attr(docsrs, doc(cfg(feature = "fee")))]
+pub mod fee;
+
+/// The admin extension can be used to expose the owner of the vault and to
+/// transfer ownership in two steps.
+#[cfg(feature = "admin")]
+#[cfg_attr(docsrs, doc(cfg(feature = "admin")))]
+pub mod admin;
+
+/// The pause extension can be used to add a circuit breaker to the vault,
+/// allowing the admin or a guardian to pause individual operations.
+#[cfg(feature = "pause")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pause")))]
+pub mod pause;
+
+/// The whitelist extension can be used by permissioned vaults that only accept
+/// deposits from a set of allowed addresses.
+#[cfg(feature = "whitelist")]
+#[cfg_attr(docsrs, doc(cfg(feature = "whitelist")))]
+pub mod whitelist;
+
+/// The caps extension can be used by vaults that limit deposits with a global
+/// cap and per address caps.
+#[cfg(feature = "caps")]
+#[cfg_attr(docsrs, doc(cfg(feature = "caps")))]
+pub mod caps;
+
+/// The autocompound extension can be used by vaults that harvest rewards and
+/// reinvest them into the base token.
+#[cfg(feature = "autocompound")]
+#[cfg_attr(docsrs, doc(cfg(feature = "autocompound")))]
+pub mod autocompound;
+
+/// The rewards extension can be used by vaults that distribute reward tokens to
+/// their depositors instead of compounding them.
+#[cfg(feature = "rewards")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rewards")))]
+pub mod rewards;
+
+/// The zap extension can be used by vaults that accept deposits and return
+/// withdrawals in tokens other than the base token, swapping them internally.
+#[cfg(feature = "zap")]
+#[cfg_attr(docsrs, doc(cfg(feature = "zap")))]
+pub mod zap;
+
+/// The oracle extension can be used to expose a manipulation resistant time
+/// weighted average share price of the vault.
+#[cfg(feature = "oracle")]
+#[cfg_attr(docsrs, doc(cfg(feature = "oracle")))]
+pub mod oracle;
+
+/// The strategies extension can be used by meta-vaults that allocate their
+/// funds across multiple sub-strategies.
+#[cfg(feature = "strategies")]
+#[cfg_attr(docsrs, doc(cfg(feature = "strategies")))]
+pub mod strategies;
+
+/// The async redeem extension can be used by vaults that can not fulfill
+/// redemptions synchronously, using a request and claim flow.
+#[cfg(feature = "async-redeem")]
+#[cfg_attr(docsrs, doc(cfg(feature = "async-redeem")))]
+pub mod async_redeem;
+
+/// The rate limit extension can be used by vaults that limit the inflow and
+/// outflow of base tokens per epoch.
+#[cfg(feature = "rate-limit")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rate-limit")))]
+pub mod rate_limit;
+
+/// The referral extension can be used to attribute deposits to referrers, who
+/// earn a share of the fees.
+#[cfg(feature = "referral")]
+#[cfg_attr(docsrs, doc(cfg(feature = "referral")))]
+pub mod referral;
+
+/// The vesting extension can be used by vaults where claimed incentives vest
+/// linearly instead of being paid out immediately.
+#[cfg(feature = "vesting")]
+#[cfg_attr(docsrs, doc(cfg(feature = "vesting")))]
+pub mod vesting;
+
+/// The migration extension can be used to move vault tokens into another vault
+/// with the same base token in a single transaction.
+#[cfg(feature = "migration")]
+#[cfg_attr(docsrs, doc(cfg(feature = "migration")))]
+pub mod migration;
+
+/// The snapshot extension can be used to record point in time vault token
+/// balances.
+#[cfg(feature = "snapshot")]
+#[cfg_attr(docsrs, doc(cfg(feature = "snapshot")))]
+pub mod snapshot;
+
+/// The voting power extension can be used to expose governance voting power
+/// derived from vault tokens.
+#[cfg(feature = "voting-power")]
+#[cfg_attr(docsrs, doc(cfg(feature = "voting-power")))]
+pub mod voting_power;
+
+/// The insurance extension can be used by vaults with an attached insurance
+/// fund covering losses of depositors.
+#[cfg(feature = "insurance")]
+#[cfg_attr(docsrs, doc(cfg(feature = "insurance")))]
+pub mod insurance;
+
+/// The emergency withdraw extension can be used to provide an escape hatch that
+/// redeems against the idle reserves of the vault only.
+#[cfg(feature = "emergency-withdraw")]
+#[cfg_attr(docsrs, doc(cfg(feature = "emergency-withdraw")))]
+pub mod emergency_withdraw;
+
+/// The performance fee extension can be used by vaults that charge a
+/// performance fee above a high-watermark.
+#[cfg(feature = "performance-fee")]
+#[cfg_attr(docsrs, doc(cfg(feature = "performance-fee")))]
+pub mod performance_fee;
+
+/// The operator extension can be used to let owners approve operators that
+/// redeem and unlock on their behalf.
+#[cfg(feature = "operator")]
+#[cfg_attr(docsrs, doc(cfg(feature = "operator")))]
+pub mod operator;
+
+/// The permit extension can be used to let relayers execute deposits and
+/// redemptions on behalf of users, authorized by off-chain signatures.
+#[cfg(feature = "permit")]
+#[cfg_attr(docsrs, doc(cfg(feature = "permit")))]
+pub mod permit;
+
+/// The leverage extension can be used by vaults that let users borrow base
+/// tokens against their vault tokens.
+#[cfg(feature = "leverage")]
+#[cfg_attr(docsrs, doc(cfg(feature = "leverage")))]
+pub mod leverage;
+
+/// The compliance extension can be used by vaults that only accept deposits from
+/// addresses holding an attested credential.
+#[cfg(feature = "compliance")]
+#[cfg_attr(docsrs, doc(cfg(feature = "compliance")))]
+pub mod compliance;
+
+/// The timelock extension can be used to require a delay between proposing and
+/// executing sensitive admin actions.
+#[cfg(feature = "timelock")]
+#[cfg_attr(docsrs, doc(cfg(feature = "timelock")))]
+pub mod timelock;
+
+/// The metrics extension can be used to expose self-reported yield metrics and
+/// the performance history of the vault.
+#[cfg(feature = "metrics")]
+#[cfg_attr(docsrs, doc(cfg(feature = "metrics")))]
+pub mod metrics;
+
+/// The accounting extension can be used to record periodic share price
+/// checkpoints on-chain.
+#[cfg(feature = "accounting")]
+#[cfg_attr(docsrs, doc(cfg(feature = "accounting")))]
+pub mod accounting;
+
+/// The schedule extension can be used to let users pre-fund recurring deposits
+/// that are executed by keepers.
+#[cfg(feature = "schedule")]
+#[cfg_attr(docsrs, doc(cfg(feature = "schedule")))]
+pub mod schedule;
+
+/// The ICA extension can be used by vaults that deposit into and redeem from
+/// remote strategies through interchain accounts.
+#[cfg(feature = "ica")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ica")))]
+pub mod ica;
+
 /// The Cw4626 extension is the only extension provided with in this repo that
 /// does not extend the standard `ExecuteMsg` and `QueryMsg` enums with by
 /// putting its variants inside of a `VaultExtension` variant. Instead it adds
@@ -0,0 +1,88 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{to_json_binary, Addr, Coin, CosmosMsg, StdResult, Uint128, WasmMsg};
+
+use crate::{ExtensionExecuteMsg, Recipient, VaultStandardExecuteMsg};
+
+/// Additional ExecuteMsg variants for vaults that enable the Operator
+/// extension. Mirrors the operator semantics of ERC-7540: an owner can approve
+/// operators that may redeem and unlock on their behalf, without the owner
+/// having to transfer their vault tokens to the operator.
+#[cw_serde]
+pub enum OperatorExecuteMsg {
+    /// Approve or revoke an operator of the caller.
+    SetOperator {
+        /// The address of the operator.
+        operator: String,
+        /// Whether the operator is approved.
+        approved: bool,
+    },
+
+    /// Redeem vault tokens of `owner`, behaving like
+    /// `VaultStandardExecuteMsg::Redeem` called by the owner. Can only be
+    /// called by an approved operator of `owner`. The vault burns the vault
+    /// tokens directly from the balance of `owner`.
+    RedeemFor {
+        /// The owner of the vault tokens.
+        owner: String,
+        /// The amount of vault tokens to redeem.
+        amount: Uint128,
+        /// An optional field containing which address should receive the
+        /// withdrawn base tokens. If not set, the caller address will be
+        /// used instead.
+        recipient: Option<Recipient>,
+        /// The minimum amount of base tokens that must be returned.
+        min_assets_out: Option<Uint128>,
+    },
+
+    /// Start unlocking vault tokens of `owner`, behaving like
+    /// `LockupExecuteMsg::Unlock` called by the owner. Can only be called by
+    /// an approved operator of `owner`. The created unlocking position is
+    /// owned by `owner`. Vaults that do not enable the Lockup extension must
+    /// return an error.
+    UnlockFor {
+        /// The owner of the vault tokens.
+        owner: String,
+        /// The amount of vault tokens to unlock.
+        amount: Uint128,
+    },
+}
+
+impl OperatorExecuteMsg {
+    /// Convert a [`OperatorExecuteMsg`] into a [`CosmosMsg`].
+    pub fn into_cosmos_msg(self, contract_addr: String, funds: Vec<Coin>) -> StdResult<CosmosMsg> {
+        Ok(WasmMsg::Execute {
+            contract_addr,
+            msg: to_json_binary(&VaultStandardExecuteMsg::VaultExtension(
+                ExtensionExecuteMsg::Operator(self),
+            ))?,
+            funds,
+        }
+        .into())
+    }
+}
+
+/// Additional QueryMsg variants for vaults that enable the Operator extension.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum OperatorQueryMsg {
+    /// Returns bool, whether `operator` is an approved operator of `owner`.
+    #[returns(bool)]
+    IsOperator {
+        /// The owner of the vault tokens.
+        owner: String,
+        /// The address of the operator.
+        operator: String,
+    },
+
+    /// Returns a `Vec<Addr>` containing the approved operators of `owner`,
+    /// ordered by address.
+    #[returns(Vec<Addr>)]
+    Operators {
+        /// The owner of the vault tokens.
+        owner: String,
+        /// Return results only after this address
+        start_after: Option<String>,
+        /// Max amount of results to return
+        limit: Option<u32>,
+    },
+}
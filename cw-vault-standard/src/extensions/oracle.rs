@@ -0,0 +1,42 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Decimal, Decimal256, Timestamp};
+
+/// Additional QueryMsg variants for vaults that enable the Oracle extension.
+///
+/// The share price is the amount of base tokens per vault token, i.e. what
+/// `ConvertToAssets` returns for one unit of vault tokens. Unlike the spot
+/// price, the time weighted average can not be moved within a single block,
+/// making it suitable for valuing vault tokens as collateral.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum OracleQueryMsg {
+    /// Returns `Decimal` time weighted average share price over the last
+    /// `window` seconds. Vaults must return an error if the accumulator does
+    /// not cover the whole window.
+    #[returns(Decimal)]
+    TwapSharePrice {
+        /// The length of the averaging window in seconds.
+        window: u64,
+    },
+
+    /// Returns `SharePriceAccumulator` containing the raw cumulative share
+    /// price, from which integrators can compute averages over arbitrary
+    /// windows by sampling it at two points in time.
+    #[returns(SharePriceAccumulator)]
+    SharePriceAccumulator {},
+}
+
+/// Returned by `OracleQueryMsg::SharePriceAccumulator` and contains the
+/// cumulative share price of the vault.
+#[cw_serde]
+pub struct SharePriceAccumulator {
+    /// The sum of the share price multiplied by the number of seconds it was
+    /// valid for, since the vault was created.
+    pub cumulative_price: Decimal256,
+    /// The block time at which `cumulative_price` was last updated.
+    pub last_updated: Timestamp,
+    /// The share price since `last_updated`. Add
+    /// `last_price * (now - last_updated)` to `cumulative_price` to get the
+    /// cumulative price at the current block time.
+    pub last_price: Decimal,
+}
@@ -0,0 +1,80 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{to_json_binary, Addr, Coin, CosmosMsg, StdResult, WasmMsg};
+
+use crate::{ExtensionExecuteMsg, VaultStandardExecuteMsg};
+
+/// An operation of the vault that can be paused.
+#[cw_serde]
+pub enum PausableOperation {
+    /// Deposits, i.e. `Deposit`, `DepositAll` and `Mint`.
+    Deposit,
+    /// Redemptions, i.e. `Redeem`, `RedeemAll` and `Withdraw`.
+    Redeem,
+    /// Unlocking of lockup positions, for vaults that enable the Lockup
+    /// extension.
+    Unlock,
+    /// A vault specific operation, described by the contained string.
+    Other(String),
+}
+
+/// Additional ExecuteMsg variants for vaults that enable the Pause extension.
+///
+/// While deposits are paused, `MaxDeposit` must return zero remaining
+/// capacity with `paused` set to true, and while redemptions are paused,
+/// `MaxWithdraw` and `MaxRedeem` must return zero. `OperationsStatus` must
+/// report paused operations as disabled.
+#[cw_serde]
+pub enum PauseExecuteMsg {
+    /// Pause the given operations of the vault. Can only be called by the
+    /// admin or the guardian of the vault. If `operations` is not set, all
+    /// operations are paused.
+    Pause {
+        /// The operations to pause.
+        operations: Option<Vec<PausableOperation>>,
+    },
+
+    /// Unpause the given operations of the vault. Can only be called by the
+    /// admin of the vault. If `operations` is not set, all operations are
+    /// unpaused.
+    Unpause {
+        /// The operations to unpause.
+        operations: Option<Vec<PausableOperation>>,
+    },
+}
+
+impl PauseExecuteMsg {
+    /// Convert a [`PauseExecuteMsg`] into a [`CosmosMsg`].
+    pub fn into_cosmos_msg(self, contract_addr: String, funds: Vec<Coin>) -> StdResult<CosmosMsg> {
+        Ok(WasmMsg::Execute {
+            contract_addr,
+            msg: to_json_binary(&VaultStandardExecuteMsg::VaultExtension(
+                ExtensionExecuteMsg::Pause(self),
+            ))?,
+            funds,
+        }
+        .into())
+    }
+}
+
+/// Additional QueryMsg variants for vaults that enable the Pause extension.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum PauseQueryMsg {
+    /// Returns bool, whether the given operation is currently paused.
+    #[returns(bool)]
+    IsPaused {
+        /// The operation to check.
+        operation: PausableOperation,
+    },
+
+    /// Returns a `Vec<PausableOperation>` containing the operations that are
+    /// currently paused.
+    #[returns(Vec<PausableOperation>)]
+    PausedOperations {},
+
+    /// Returns an `Option<Addr>` containing the guardian of the vault, which
+    /// can pause but not unpause operations, or `None` if only the admin can
+    /// pause the vault.
+    #[returns(Option<Addr>)]
+    Guardian {},
+}
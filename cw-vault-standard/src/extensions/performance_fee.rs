@@ -0,0 +1,61 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{
+    to_json_binary, Coin, CosmosMsg, Decimal, StdResult, Timestamp, Uint128, WasmMsg,
+};
+
+use crate::{ExtensionExecuteMsg, VaultStandardExecuteMsg};
+
+/// Additional ExecuteMsg variants for vaults that enable the PerformanceFee
+/// extension.
+#[cw_serde]
+pub enum PerformanceFeeExecuteMsg {
+    /// Charge the accrued performance fees and raise the high-watermark to the
+    /// current share price. Callable by anyone, e.g. keepers, once the
+    /// crystallization period has passed since the last crystallization.
+    CrystallizeFees {},
+}
+
+impl PerformanceFeeExecuteMsg {
+    /// Convert a [`PerformanceFeeExecuteMsg`] into a [`CosmosMsg`].
+    pub fn into_cosmos_msg(self, contract_addr: String, funds: Vec<Coin>) -> StdResult<CosmosMsg> {
+        Ok(WasmMsg::Execute {
+            contract_addr,
+            msg: to_json_binary(&VaultStandardExecuteMsg::VaultExtension(
+                ExtensionExecuteMsg::PerformanceFee(self),
+            ))?,
+            funds,
+        }
+        .into())
+    }
+}
+
+/// Additional QueryMsg variants for vaults that enable the PerformanceFee
+/// extension.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum PerformanceFeeQueryMsg {
+    /// Returns `PerformanceFeeStateResponse` containing the high-watermark,
+    /// the crystallization schedule and the accrued performance fees.
+    #[returns(PerformanceFeeStateResponse)]
+    PerformanceFeeState {},
+}
+
+/// Returned by `PerformanceFeeQueryMsg::PerformanceFeeState` and contains the
+/// state of the high-watermark performance fee of the vault.
+#[cw_serde]
+pub struct PerformanceFeeStateResponse {
+    /// The performance fee, as a fraction of the yield above the
+    /// high-watermark.
+    pub performance_fee: Decimal,
+    /// The highest share price, in base tokens per vault token, at which
+    /// performance fees were crystallized. Fees are only charged on yield
+    /// above this price.
+    pub high_watermark: Decimal,
+    /// The minimum number of seconds between two crystallizations.
+    pub crystallization_period: u64,
+    /// The block time of the last crystallization.
+    pub last_crystallization: Timestamp,
+    /// The amount of base tokens of performance fees that have accrued since
+    /// the last crystallization but have not been charged yet.
+    pub accrued_fees: Uint128,
+}
@@ -0,0 +1,126 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{
+    to_json_binary, to_json_vec, Api, Binary, Coin, CosmosMsg, StdResult, Timestamp, Uint128,
+    WasmMsg,
+};
+use sha2::{Digest, Sha256};
+
+use crate::{ExtensionExecuteMsg, VaultStandardExecuteMsg};
+
+/// Additional ExecuteMsg variants for vaults that enable the Permit extension.
+#[cw_serde]
+pub enum PermitExecuteMsg {
+    /// Execute an action on behalf of `sign_doc.owner`, authorized by a
+    /// signature of the owner over the sign doc. Callable by anyone, e.g. a
+    /// relayer paying the gas fees. Vaults must verify that:
+    /// - `signature` is a valid signature of [`PermitSignDoc::hash`] by
+    ///   `public_key`, see [`PermitSignDoc::verify`],
+    /// - `public_key` corresponds to the address of the owner,
+    /// - `chain_id` and `contract` match the current chain and the vault,
+    /// - `nonce` equals the current nonce of the owner, which is then
+    ///   incremented,
+    /// - the block time is before `expiry`.
+    ///
+    /// Base tokens for `PermitAction::Deposit` must be provided by the caller,
+    /// either in the funds field or through a cw20 allowance granted by the
+    /// owner.
+    ExecuteWithPermit {
+        /// The signed permit.
+        sign_doc: PermitSignDoc,
+        /// The secp256k1 signature of the owner over the hash of `sign_doc`.
+        signature: Binary,
+        /// The secp256k1 public key of the owner.
+        public_key: Binary,
+    },
+
+    /// Increment the permit nonce of the caller, invalidating all permits
+    /// signed with the current nonce.
+    InvalidateNonce {},
+}
+
+impl PermitExecuteMsg {
+    /// Convert a [`PermitExecuteMsg`] into a [`CosmosMsg`].
+    pub fn into_cosmos_msg(self, contract_addr: String, funds: Vec<Coin>) -> StdResult<CosmosMsg> {
+        Ok(WasmMsg::Execute {
+            contract_addr,
+            msg: to_json_binary(&VaultStandardExecuteMsg::VaultExtension(
+                ExtensionExecuteMsg::Permit(self),
+            ))?,
+            funds,
+        }
+        .into())
+    }
+}
+
+/// Additional QueryMsg variants for vaults that enable the Permit extension.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum PermitQueryMsg {
+    /// Returns u64, the nonce that the next permit of `owner` must be signed
+    /// with.
+    #[returns(u64)]
+    PermitNonce {
+        /// The address of the owner.
+        owner: String,
+    },
+}
+
+/// The action authorized by a permit.
+#[cw_serde]
+pub enum PermitAction {
+    /// Deposit base tokens, behaving like `VaultStandardExecuteMsg::Deposit`
+    /// called by the owner.
+    Deposit {
+        /// The amount of base tokens to deposit.
+        amount: Uint128,
+        /// The minimum amount of vault tokens that must be minted.
+        min_shares_out: Option<Uint128>,
+    },
+    /// Redeem vault tokens of the owner, behaving like
+    /// `VaultStandardExecuteMsg::Redeem` called by the owner. The vault burns
+    /// the vault tokens directly from the balance of the owner.
+    Redeem {
+        /// The amount of vault tokens to redeem.
+        amount: Uint128,
+        /// The minimum amount of base tokens that must be returned.
+        min_assets_out: Option<Uint128>,
+    },
+}
+
+/// The canonical document signed by the owner to create a permit. The signed
+/// bytes are the SHA-256 hash of the JSON serialization of this struct, with
+/// the fields in the order they are declared.
+#[cw_serde]
+pub struct PermitSignDoc {
+    /// The chain ID the permit is valid on.
+    pub chain_id: String,
+    /// The address of the vault the permit is valid for.
+    pub contract: String,
+    /// The address of the owner that signed the permit.
+    pub owner: String,
+    /// The permit nonce of the owner, see `PermitQueryMsg::PermitNonce`.
+    pub nonce: u64,
+    /// The block time after which the permit can no longer be used.
+    pub expiry: Timestamp,
+    /// The action authorized by the permit.
+    pub action: PermitAction,
+    /// An optional field containing which address should receive the minted
+    /// vault tokens or withdrawn base tokens. If not set, the owner will be
+    /// used instead.
+    pub recipient: Option<String>,
+}
+
+impl PermitSignDoc {
+    /// Returns the SHA-256 hash of the canonical JSON serialization of the
+    /// sign doc, which is the message signed by the owner.
+    pub fn hash(&self) -> StdResult<[u8; 32]> {
+        Ok(Sha256::digest(to_json_vec(self)?).into())
+    }
+
+    /// Verifies that `signature` is a valid secp256k1 signature of the sign
+    /// doc by `public_key`. This does not check that `public_key` belongs to
+    /// `owner`.
+    pub fn verify(&self, api: &dyn Api, signature: &[u8], public_key: &[u8]) -> StdResult<bool> {
+        Ok(api.secp256k1_verify(&self.hash()?, signature, public_key)?)
+    }
+}
@@ -0,0 +1,75 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{to_json_binary, Coin, CosmosMsg, StdResult, Timestamp, Uint128, WasmMsg};
+
+use crate::{ExtensionExecuteMsg, VaultStandardExecuteMsg};
+
+/// Additional ExecuteMsg variants for vaults that enable the RateLimit
+/// extension.
+///
+/// The remaining quota of the current epoch must be reflected by the standard
+/// queries: `MaxDeposit` must not return more than the remaining inflow, and
+/// `MaxWithdraw` and `MaxRedeem` must not return more than the remaining
+/// outflow.
+#[cw_serde]
+pub enum RateLimitExecuteMsg {
+    /// Update the rate limits of the vault. Can only be called by the admin of
+    /// the vault. Takes effect from the next epoch.
+    UpdateRateLimits {
+        /// The new rate limits.
+        config: RateLimitConfig,
+    },
+}
+
+impl RateLimitExecuteMsg {
+    /// Convert a [`RateLimitExecuteMsg`] into a [`CosmosMsg`].
+    pub fn into_cosmos_msg(self, contract_addr: String, funds: Vec<Coin>) -> StdResult<CosmosMsg> {
+        Ok(WasmMsg::Execute {
+            contract_addr,
+            msg: to_json_binary(&VaultStandardExecuteMsg::VaultExtension(
+                ExtensionExecuteMsg::RateLimit(self),
+            ))?,
+            funds,
+        }
+        .into())
+    }
+}
+
+/// Additional QueryMsg variants for vaults that enable the RateLimit
+/// extension.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum RateLimitQueryMsg {
+    /// Returns `RateLimitsResponse` containing the rate limits of the vault
+    /// and the remaining quota of the current epoch.
+    #[returns(RateLimitsResponse)]
+    RateLimits {},
+}
+
+/// The limits on the flow of base tokens into and out of the vault per epoch.
+#[cw_serde]
+pub struct RateLimitConfig {
+    /// The length of an epoch in seconds.
+    pub epoch_duration: u64,
+    /// The maximum amount of base tokens that can be deposited per epoch, or
+    /// `None` if deposits are not limited.
+    pub max_inflow: Option<Uint128>,
+    /// The maximum amount of base tokens that can be withdrawn per epoch, or
+    /// `None` if withdrawals are not limited.
+    pub max_outflow: Option<Uint128>,
+}
+
+/// Returned by `RateLimitQueryMsg::RateLimits` and contains the rate limits of
+/// the vault and the state of the current epoch.
+#[cw_serde]
+pub struct RateLimitsResponse {
+    /// The rate limits of the vault.
+    pub config: RateLimitConfig,
+    /// The block time at which the current epoch started.
+    pub epoch_start: Timestamp,
+    /// The amount of base tokens that can still be deposited in the current
+    /// epoch, or `None` if deposits are not limited.
+    pub inflow_remaining: Option<Uint128>,
+    /// The amount of base tokens that can still be withdrawn in the current
+    /// epoch, or `None` if withdrawals are not limited.
+    pub outflow_remaining: Option<Uint128>,
+}
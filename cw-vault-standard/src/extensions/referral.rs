@@ -0,0 +1,90 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{to_json_binary, Addr, Coin, CosmosMsg, Decimal, StdResult, Uint128, WasmMsg};
+
+use crate::{ExtensionExecuteMsg, Recipient, TokenAmount, VaultStandardExecuteMsg};
+
+/// Additional ExecuteMsg variants for vaults that enable the Referral
+/// extension.
+#[cw_serde]
+pub enum ReferralExecuteMsg {
+    /// Deposit base tokens into the vault, attributing the deposit to a
+    /// referrer. Behaves exactly like `VaultStandardExecuteMsg::Deposit`, and
+    /// the referrer earns a share of the fees charged on the deposited amount,
+    /// see `ReferralQueryMsg::ReferralConfig`.
+    DepositWithReferral {
+        /// The amount of base tokens to deposit.
+        amount: Uint128,
+        /// The referrer, either an address or a code registered through
+        /// `RegisterCode`.
+        referrer: String,
+        /// An optional field containing the recipient of the vault tokens. If
+        /// not set, the caller address will be used instead.
+        recipient: Option<Recipient>,
+        /// The minimum amount of vault tokens that must be minted.
+        min_shares_out: Option<Uint128>,
+    },
+
+    /// Register a referral code for the caller, which can be used instead of
+    /// the caller address in `DepositWithReferral`. Vaults must return an
+    /// error if the code is already taken.
+    RegisterCode {
+        /// The code to register.
+        code: String,
+    },
+
+    /// Claim the referral earnings of the caller, as returned by
+    /// `ReferralQueryMsg::ReferralEarnings`.
+    ClaimReferralEarnings {
+        /// An optional field containing which address should receive the
+        /// earnings. If not set, the caller address will be used instead.
+        recipient: Option<String>,
+    },
+}
+
+impl ReferralExecuteMsg {
+    /// Convert a [`ReferralExecuteMsg`] into a [`CosmosMsg`].
+    pub fn into_cosmos_msg(self, contract_addr: String, funds: Vec<Coin>) -> StdResult<CosmosMsg> {
+        Ok(WasmMsg::Execute {
+            contract_addr,
+            msg: to_json_binary(&VaultStandardExecuteMsg::VaultExtension(
+                ExtensionExecuteMsg::Referral(self),
+            ))?,
+            funds,
+        }
+        .into())
+    }
+}
+
+/// Additional QueryMsg variants for vaults that enable the Referral extension.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum ReferralQueryMsg {
+    /// Returns a `Vec<TokenAmount>` containing the unclaimed referral earnings
+    /// of `referrer`.
+    #[returns(Vec<TokenAmount>)]
+    ReferralEarnings {
+        /// The address of the referrer.
+        referrer: String,
+    },
+
+    /// Returns an `Option<Addr>` containing the referrer that registered the
+    /// given code, or `None` if the code is not registered.
+    #[returns(Option<Addr>)]
+    ReferrerByCode {
+        /// The referral code.
+        code: String,
+    },
+
+    /// Returns `ReferralConfig` containing the referral parameters of the
+    /// vault.
+    #[returns(ReferralConfig)]
+    ReferralConfig {},
+}
+
+/// The referral parameters of the vault.
+#[cw_serde]
+pub struct ReferralConfig {
+    /// The share of the fees charged on a referred deposit that is paid to the
+    /// referrer.
+    pub fee_share: Decimal,
+}
@@ -0,0 +1,53 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{to_json_binary, Coin, CosmosMsg, StdResult, WasmMsg};
+
+use crate::{ExtensionExecuteMsg, Token, TokenAmount, VaultStandardExecuteMsg};
+
+/// Type for the event emitted on call to `ClaimRewards`.
+pub const REWARDS_CLAIMED_EVENT_TYPE: &str = "rewards_claimed";
+
+/// Additional ExecuteMsg variants for vaults that enable the Rewards extension.
+#[cw_serde]
+pub enum RewardsExecuteMsg {
+    /// Claim the pending rewards of the caller, as returned by
+    /// `RewardsQueryMsg::PendingRewards`. Emits an event with type
+    /// `REWARDS_CLAIMED_EVENT_TYPE`.
+    ClaimRewards {
+        /// An optional field containing which address should receive the
+        /// claimed rewards. If not set, the caller address will be used
+        /// instead.
+        recipient: Option<String>,
+    },
+}
+
+impl RewardsExecuteMsg {
+    /// Convert a [`RewardsExecuteMsg`] into a [`CosmosMsg`].
+    pub fn into_cosmos_msg(self, contract_addr: String, funds: Vec<Coin>) -> StdResult<CosmosMsg> {
+        Ok(WasmMsg::Execute {
+            contract_addr,
+            msg: to_json_binary(&VaultStandardExecuteMsg::VaultExtension(
+                ExtensionExecuteMsg::Rewards(self),
+            ))?,
+            funds,
+        }
+        .into())
+    }
+}
+
+/// Additional QueryMsg variants for vaults that enable the Rewards extension.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum RewardsQueryMsg {
+    /// Returns a `Vec<TokenAmount>` containing the rewards that `owner` could
+    /// claim right now. Reward tokens without pending rewards are omitted.
+    #[returns(Vec<TokenAmount>)]
+    PendingRewards {
+        /// The address to query the pending rewards of.
+        owner: String,
+    },
+
+    /// Returns a `Vec<Token>` containing the tokens that are distributed as
+    /// rewards to the holders of the vault tokens.
+    #[returns(Vec<Token>)]
+    RewardTokens {},
+}
@@ -0,0 +1,100 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{to_json_binary, Addr, Coin, CosmosMsg, StdResult, Uint128, WasmMsg};
+use cw_utils::{Duration, Expiration};
+
+use crate::{ExtensionExecuteMsg, VaultStandardExecuteMsg};
+
+/// Additional ExecuteMsg variants for vaults that enable the Schedule
+/// extension.
+#[cw_serde]
+pub enum ScheduleExecuteMsg {
+    /// Create a schedule depositing `amount_per_period` base tokens every
+    /// `interval`. The base tokens for all periods must be sent in the funds
+    /// field and are held by the vault until they are deposited. The first
+    /// deposit can be executed immediately.
+    CreateSchedule {
+        /// The amount of base tokens deposited per period.
+        amount_per_period: Uint128,
+        /// The interval between two deposits, in blocks or seconds.
+        interval: Duration,
+        /// An optional field containing which address should receive the
+        /// minted vault tokens. If not set, the caller address will be used
+        /// instead.
+        recipient: Option<String>,
+    },
+
+    /// Execute the next deposit of a schedule that is due. Callable by anyone,
+    /// e.g. keepers discovering schedules through
+    /// `ScheduleQueryMsg::DueSchedules`.
+    ExecuteSchedule {
+        /// The ID of the schedule.
+        schedule_id: u64,
+    },
+
+    /// Cancel a schedule, refunding the base tokens that have not been
+    /// deposited yet. Can only be called by the owner of the schedule.
+    CancelSchedule {
+        /// The ID of the schedule.
+        schedule_id: u64,
+    },
+}
+
+impl ScheduleExecuteMsg {
+    /// Convert a [`ScheduleExecuteMsg`] into a [`CosmosMsg`].
+    pub fn into_cosmos_msg(self, contract_addr: String, funds: Vec<Coin>) -> StdResult<CosmosMsg> {
+        Ok(WasmMsg::Execute {
+            contract_addr,
+            msg: to_json_binary(&VaultStandardExecuteMsg::VaultExtension(
+                ExtensionExecuteMsg::Schedule(self),
+            ))?,
+            funds,
+        }
+        .into())
+    }
+}
+
+/// Additional QueryMsg variants for vaults that enable the Schedule extension.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum ScheduleQueryMsg {
+    /// Returns a `Vec<DepositSchedule>` containing the active schedules of
+    /// `owner`, ordered by ID.
+    #[returns(Vec<DepositSchedule>)]
+    Schedules {
+        /// The owner of the schedules.
+        owner: String,
+        /// Return results only after this schedule ID
+        start_after: Option<u64>,
+        /// Max amount of results to return
+        limit: Option<u32>,
+    },
+
+    /// Returns a `Vec<DepositSchedule>` containing the active schedules of all
+    /// owners whose next deposit is due, ordered by ID.
+    #[returns(Vec<DepositSchedule>)]
+    DueSchedules {
+        /// Return results only after this schedule ID
+        start_after: Option<u64>,
+        /// Max amount of results to return
+        limit: Option<u32>,
+    },
+}
+
+/// A schedule of recurring deposits.
+#[cw_serde]
+pub struct DepositSchedule {
+    /// The ID of the schedule.
+    pub id: u64,
+    /// The owner of the schedule.
+    pub owner: Addr,
+    /// The recipient of the minted vault tokens.
+    pub recipient: Addr,
+    /// The amount of base tokens deposited per period.
+    pub amount_per_period: Uint128,
+    /// The interval between two deposits.
+    pub interval: Duration,
+    /// The amount of base tokens that have not been deposited yet.
+    pub remaining: Uint128,
+    /// When the next deposit can be executed.
+    pub next_execution: Expiration,
+}
@@ -0,0 +1,78 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{to_json_binary, Coin, CosmosMsg, StdResult, Timestamp, Uint128, WasmMsg};
+
+use crate::{ExtensionExecuteMsg, VaultStandardExecuteMsg};
+
+/// Type for the event emitted on call to `CreateSnapshot`.
+pub const SNAPSHOT_CREATED_EVENT_TYPE: &str = "snapshot_created";
+/// Key for the attribute containing the ID of the created snapshot in the
+/// "snapshot created" event.
+pub const SNAPSHOT_ID_ATTR_KEY: &str = "snapshot_id";
+
+/// Additional ExecuteMsg variants for vaults that enable the Snapshot
+/// extension.
+#[cw_serde]
+pub enum SnapshotExecuteMsg {
+    /// Record the vault token balances of all owners and the total supply at
+    /// the current block. Can only be called by the admin or keepers of the
+    /// vault. Emits an event with type `SNAPSHOT_CREATED_EVENT_TYPE` with an
+    /// attribute with key `SNAPSHOT_ID_ATTR_KEY` containing the u64 ID of the
+    /// snapshot.
+    CreateSnapshot {},
+}
+
+impl SnapshotExecuteMsg {
+    /// Convert a [`SnapshotExecuteMsg`] into a [`CosmosMsg`].
+    pub fn into_cosmos_msg(self, contract_addr: String, funds: Vec<Coin>) -> StdResult<CosmosMsg> {
+        Ok(WasmMsg::Execute {
+            contract_addr,
+            msg: to_json_binary(&VaultStandardExecuteMsg::VaultExtension(
+                ExtensionExecuteMsg::Snapshot(self),
+            ))?,
+            funds,
+        }
+        .into())
+    }
+}
+
+/// Additional QueryMsg variants for vaults that enable the Snapshot extension.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum SnapshotQueryMsg {
+    /// Returns `Uint128` vault token balance of `owner` at the given snapshot.
+    #[returns(Uint128)]
+    BalanceAtSnapshot {
+        /// The owner of the vault tokens.
+        owner: String,
+        /// The ID of the snapshot.
+        snapshot_id: u64,
+    },
+
+    /// Returns `Uint128` total supply of vault tokens at the given snapshot.
+    #[returns(Uint128)]
+    TotalSupplyAtSnapshot {
+        /// The ID of the snapshot.
+        snapshot_id: u64,
+    },
+
+    /// Returns a `Vec<SnapshotInfo>` containing the snapshots of the vault,
+    /// ordered by ID.
+    #[returns(Vec<SnapshotInfo>)]
+    Snapshots {
+        /// Return results only after this snapshot ID
+        start_after: Option<u64>,
+        /// Max amount of results to return
+        limit: Option<u32>,
+    },
+}
+
+/// Information about a snapshot.
+#[cw_serde]
+pub struct SnapshotInfo {
+    /// The ID of the snapshot.
+    pub id: u64,
+    /// The block height at which the snapshot was taken.
+    pub height: u64,
+    /// The block time at which the snapshot was taken.
+    pub time: Timestamp,
+}
@@ -0,0 +1,88 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{to_json_binary, Addr, Coin, CosmosMsg, Decimal, StdResult, Uint128, WasmMsg};
+
+use crate::{ExtensionExecuteMsg, VaultStandardExecuteMsg};
+
+/// Additional ExecuteMsg variants for vaults that enable the Strategies
+/// extension.
+#[cw_serde]
+pub enum StrategiesExecuteMsg {
+    /// Add a sub-strategy to the vault with the given target weight. Can only
+    /// be called by the admin of the vault. Funds are only moved into the
+    /// strategy on the next `Rebalance`.
+    AddStrategy {
+        /// The address of the strategy, e.g. another vault.
+        address: String,
+        /// The target share of the total assets of the vault allocated to the
+        /// strategy.
+        weight: Decimal,
+    },
+
+    /// Remove a sub-strategy from the vault, withdrawing all funds deployed in
+    /// it. Can only be called by the admin of the vault.
+    RemoveStrategy {
+        /// The address of the strategy.
+        address: String,
+    },
+
+    /// Update the target weights of the given sub-strategies. Can only be
+    /// called by the admin of the vault. Vaults must return an error if the
+    /// sum of the weights of all strategies exceeds one.
+    UpdateWeights {
+        /// The addresses of the strategies and their new target weights.
+        weights: Vec<(String, Decimal)>,
+    },
+
+    /// Move funds between the sub-strategies to match their target weights.
+    /// Can only be called by the admin or keepers of the vault.
+    Rebalance {},
+}
+
+impl StrategiesExecuteMsg {
+    /// Convert a [`StrategiesExecuteMsg`] into a [`CosmosMsg`].
+    pub fn into_cosmos_msg(self, contract_addr: String, funds: Vec<Coin>) -> StdResult<CosmosMsg> {
+        Ok(WasmMsg::Execute {
+            contract_addr,
+            msg: to_json_binary(&VaultStandardExecuteMsg::VaultExtension(
+                ExtensionExecuteMsg::Strategies(self),
+            ))?,
+            funds,
+        }
+        .into())
+    }
+}
+
+/// Additional QueryMsg variants for vaults that enable the Strategies
+/// extension.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum StrategiesQueryMsg {
+    /// Returns a `Vec<StrategyInfo>` containing the sub-strategies of the
+    /// vault, ordered by address.
+    #[returns(Vec<StrategyInfo>)]
+    Strategies {
+        /// Return results only after this address
+        start_after: Option<String>,
+        /// Max amount of results to return
+        limit: Option<u32>,
+    },
+
+    /// Returns `StrategyInfo` of the sub-strategy with the given address.
+    #[returns(StrategyInfo)]
+    Strategy {
+        /// The address of the strategy.
+        address: String,
+    },
+}
+
+/// A sub-strategy of the vault.
+#[cw_serde]
+pub struct StrategyInfo {
+    /// The address of the strategy.
+    pub address: Addr,
+    /// The target share of the total assets of the vault allocated to the
+    /// strategy.
+    pub weight: Decimal,
+    /// The amount of base tokens currently deployed in the strategy.
+    pub deployed: Uint128,
+}
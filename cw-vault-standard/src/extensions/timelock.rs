@@ -0,0 +1,87 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{to_json_binary, Addr, Binary, Coin, CosmosMsg, StdResult, Timestamp, WasmMsg};
+
+use crate::{ExtensionExecuteMsg, VaultStandardExecuteMsg};
+
+/// Additional ExecuteMsg variants for vaults that enable the Timelock
+/// extension.
+///
+/// In vaults that enable this extension, sensitive admin messages, e.g.
+/// `FeeExecuteMsg::UpdateFees` or `StrategiesExecuteMsg::AddStrategy`, can not
+/// be called directly. They must be proposed through `ProposeAction` and can
+/// only be executed once the timelock delay has passed, giving depositors time
+/// to react.
+#[cw_serde]
+pub enum TimelockExecuteMsg {
+    /// Propose an admin action. Can only be called by the admin of the vault.
+    /// The action can be executed through `ExecuteAction` once the delay
+    /// returned by `TimelockQueryMsg::TimelockDelay` has passed.
+    ProposeAction {
+        /// The JSON encoded `ExecuteMsg` of the vault to execute.
+        msg: Binary,
+        /// A human readable description of the action.
+        description: String,
+    },
+
+    /// Execute a proposed action whose delay has passed. Can only be called by
+    /// the admin of the vault.
+    ExecuteAction {
+        /// The ID of the action.
+        action_id: u64,
+    },
+
+    /// Cancel a proposed action. Can only be called by the admin of the vault.
+    CancelAction {
+        /// The ID of the action.
+        action_id: u64,
+    },
+}
+
+impl TimelockExecuteMsg {
+    /// Convert a [`TimelockExecuteMsg`] into a [`CosmosMsg`].
+    pub fn into_cosmos_msg(self, contract_addr: String, funds: Vec<Coin>) -> StdResult<CosmosMsg> {
+        Ok(WasmMsg::Execute {
+            contract_addr,
+            msg: to_json_binary(&VaultStandardExecuteMsg::VaultExtension(
+                ExtensionExecuteMsg::Timelock(self),
+            ))?,
+            funds,
+        }
+        .into())
+    }
+}
+
+/// Additional QueryMsg variants for vaults that enable the Timelock extension.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum TimelockQueryMsg {
+    /// Returns a `Vec<PendingAction>` containing the proposed actions that
+    /// have not been executed or cancelled, ordered by ID.
+    #[returns(Vec<PendingAction>)]
+    PendingActions {
+        /// Return results only after this action ID
+        start_after: Option<u64>,
+        /// Max amount of results to return
+        limit: Option<u32>,
+    },
+
+    /// Returns u64, the number of seconds between proposing and executing an
+    /// action.
+    #[returns(u64)]
+    TimelockDelay {},
+}
+
+/// A proposed admin action.
+#[cw_serde]
+pub struct PendingAction {
+    /// The ID of the action.
+    pub id: u64,
+    /// The JSON encoded `ExecuteMsg` of the vault to execute.
+    pub msg: Binary,
+    /// A human readable description of the action.
+    pub description: String,
+    /// The address that proposed the action.
+    pub proposer: Addr,
+    /// The block time from which the action can be executed.
+    pub executable_at: Timestamp,
+}
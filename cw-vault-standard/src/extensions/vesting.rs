@@ -0,0 +1,87 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{to_json_binary, Coin, CosmosMsg, StdResult, Timestamp, Uint128, WasmMsg};
+
+use crate::{ExtensionExecuteMsg, Token, TokenAmount, VaultStandardExecuteMsg};
+
+/// Additional ExecuteMsg variants for vaults that enable the Vesting extension.
+///
+/// In vaults that enable this extension, claimed incentives, e.g. through
+/// `RewardsExecuteMsg::ClaimRewards`, are not paid out immediately but create
+/// a vesting position that vests linearly according to the schedule returned
+/// by `VestingQueryMsg::VestingSchedule`.
+#[cw_serde]
+pub enum VestingExecuteMsg {
+    /// Claim the vested amounts of all vesting positions of the caller.
+    ClaimVested {},
+}
+
+impl VestingExecuteMsg {
+    /// Convert a [`VestingExecuteMsg`] into a [`CosmosMsg`].
+    pub fn into_cosmos_msg(self, contract_addr: String, funds: Vec<Coin>) -> StdResult<CosmosMsg> {
+        Ok(WasmMsg::Execute {
+            contract_addr,
+            msg: to_json_binary(&VaultStandardExecuteMsg::VaultExtension(
+                ExtensionExecuteMsg::Vesting(self),
+            ))?,
+            funds,
+        }
+        .into())
+    }
+}
+
+/// Additional QueryMsg variants for vaults that enable the Vesting extension.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum VestingQueryMsg {
+    /// Returns a `Vec<VestingPosition>` containing the vesting positions of
+    /// `owner`, ordered by ID.
+    #[returns(Vec<VestingPosition>)]
+    VestingPositions {
+        /// The owner of the positions.
+        owner: String,
+        /// Return results only after this position ID
+        start_after: Option<u64>,
+        /// Max amount of results to return
+        limit: Option<u32>,
+    },
+
+    /// Returns a `Vec<TokenAmount>` containing the amounts `owner` could claim
+    /// through `VestingExecuteMsg::ClaimVested` right now.
+    #[returns(Vec<TokenAmount>)]
+    ClaimableVested {
+        /// The owner of the positions.
+        owner: String,
+    },
+
+    /// Returns `VestingSchedule` applied to newly created vesting positions.
+    #[returns(VestingSchedule)]
+    VestingSchedule {},
+}
+
+/// The schedule on which vesting positions vest.
+#[cw_serde]
+pub struct VestingSchedule {
+    /// The duration in seconds over which a position vests linearly.
+    pub duration: u64,
+    /// The duration in seconds after the start of a position during which
+    /// nothing vests. The amount that vested during the cliff becomes
+    /// claimable at its end.
+    pub cliff: u64,
+}
+
+/// A position of tokens vesting linearly.
+#[cw_serde]
+pub struct VestingPosition {
+    /// The ID of the position.
+    pub id: u64,
+    /// The vesting token.
+    pub token: Token,
+    /// The total amount of tokens vesting in the position.
+    pub total: Uint128,
+    /// The amount of tokens that have already been claimed.
+    pub claimed: Uint128,
+    /// The block time at which vesting starts.
+    pub start: Timestamp,
+    /// The block time at which the position has fully vested.
+    pub end: Timestamp,
+}
@@ -0,0 +1,53 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::Uint128;
+
+/// Additional QueryMsg variants for vaults that enable the VotingPower
+/// extension.
+///
+/// The voting power of an owner is derived from their vault tokens, and may be
+/// boosted by lockups, see `LockupQueryMsg::Boost`. The response types match
+/// those of the DAO DAO voting module interface, so that a voting module can
+/// forward the queries to the vault without conversion.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum VotingPowerQueryMsg {
+    /// Returns `VotingPowerAtHeightResponse` containing the voting power of
+    /// `owner` at the given height. If `height` is not set, the current height
+    /// is used.
+    #[returns(VotingPowerAtHeightResponse)]
+    VotingPower {
+        /// The address to query the voting power of.
+        owner: String,
+        /// The block height to query the voting power at.
+        height: Option<u64>,
+    },
+
+    /// Returns `TotalPowerAtHeightResponse` containing the total voting power
+    /// at the given height. If `height` is not set, the current height is
+    /// used.
+    #[returns(TotalPowerAtHeightResponse)]
+    TotalVotingPower {
+        /// The block height to query the total voting power at.
+        height: Option<u64>,
+    },
+}
+
+/// Returned by `VotingPowerQueryMsg::VotingPower`. Serializes the same as the
+/// response of the `VotingPowerAtHeight` query of DAO DAO voting modules.
+#[cw_serde]
+pub struct VotingPowerAtHeightResponse {
+    /// The voting power.
+    pub power: Uint128,
+    /// The block height the voting power was queried at.
+    pub height: u64,
+}
+
+/// Returned by `VotingPowerQueryMsg::TotalVotingPower`. Serializes the same as
+/// the response of the `TotalPowerAtHeight` query of DAO DAO voting modules.
+#[cw_serde]
+pub struct TotalPowerAtHeightResponse {
+    /// The total voting power.
+    pub power: Uint128,
+    /// The block height the total voting power was queried at.
+    pub height: u64,
+}
@@ -0,0 +1,66 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{to_json_binary, Addr, Coin, CosmosMsg, StdResult, WasmMsg};
+
+use crate::{ExtensionExecuteMsg, VaultStandardExecuteMsg};
+
+/// Additional ExecuteMsg variants for vaults that enable the Whitelist
+/// extension.
+///
+/// Vaults that enable this extension must return an error on `Deposit` and
+/// its variants if the recipient of the vault tokens is not whitelisted, and
+/// `MaxDeposit` must return zero remaining capacity for such recipients.
+#[cw_serde]
+pub enum WhitelistExecuteMsg {
+    /// Add the given addresses to the whitelist of depositors. Can only be
+    /// called by the admin of the vault.
+    AddToWhitelist {
+        /// The addresses to add.
+        addresses: Vec<String>,
+    },
+
+    /// Remove the given addresses from the whitelist of depositors. Can only
+    /// be called by the admin of the vault. Vault tokens that were already
+    /// issued to the addresses can still be redeemed.
+    RemoveFromWhitelist {
+        /// The addresses to remove.
+        addresses: Vec<String>,
+    },
+}
+
+impl WhitelistExecuteMsg {
+    /// Convert a [`WhitelistExecuteMsg`] into a [`CosmosMsg`].
+    pub fn into_cosmos_msg(self, contract_addr: String, funds: Vec<Coin>) -> StdResult<CosmosMsg> {
+        Ok(WasmMsg::Execute {
+            contract_addr,
+            msg: to_json_binary(&VaultStandardExecuteMsg::VaultExtension(
+                ExtensionExecuteMsg::Whitelist(self),
+            ))?,
+            funds,
+        }
+        .into())
+    }
+}
+
+/// Additional QueryMsg variants for vaults that enable the Whitelist
+/// extension.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum WhitelistQueryMsg {
+    /// Returns bool, whether the given address is allowed to deposit into the
+    /// vault.
+    #[returns(bool)]
+    IsWhitelisted {
+        /// The address to check.
+        address: String,
+    },
+
+    /// Returns a `Vec<Addr>` containing the whitelisted depositors, ordered by
+    /// address.
+    #[returns(Vec<Addr>)]
+    Whitelist {
+        /// Return results only after this address
+        start_after: Option<String>,
+        /// Max amount of results to return
+        limit: Option<u32>,
+    },
+}
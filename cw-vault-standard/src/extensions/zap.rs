@@ -0,0 +1,102 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{to_json_binary, Binary, Coin, CosmosMsg, StdResult, Uint128, WasmMsg};
+
+use crate::{ExtensionExecuteMsg, Recipient, TokenUnchecked, VaultStandardExecuteMsg};
+
+/// Additional ExecuteMsg variants for vaults that enable the Zap extension.
+#[cw_serde]
+pub enum ZapExecuteMsg {
+    /// Deposit any token into the vault. The vault swaps `asset` into the base
+    /// token and deposits it, minting vault tokens to the recipient. Native
+    /// tokens are passed in the funds parameter, cw20 tokens must be approved
+    /// first.
+    ZapIn {
+        /// The token to deposit.
+        asset: TokenUnchecked,
+        /// The amount of `asset` to deposit.
+        amount: Uint128,
+        /// The minimum amount of vault tokens that must be minted. If fewer
+        /// vault tokens would be minted, the vault must fail with
+        /// [`crate::VaultStandardError::MinOutputNotMet`].
+        min_shares_out: Option<Uint128>,
+        /// An optional swap route from `asset` into the base token, in a vault
+        /// specific encoding. If not set, the vault picks the route.
+        route: Option<Binary>,
+        /// An optional field containing which address should receive the
+        /// minted vault tokens. If not set, the caller address will be used
+        /// instead.
+        recipient: Option<Recipient>,
+    },
+
+    /// Redeem vault tokens and receive any token. The vault redeems `shares`
+    /// and swaps the withdrawn base tokens into `ask_asset`. The native vault
+    /// token must be passed in the funds parameter.
+    ZapOut {
+        /// The amount of vault tokens to redeem.
+        shares: Uint128,
+        /// The token to receive.
+        ask_asset: TokenUnchecked,
+        /// The minimum amount of `ask_asset` that must be returned. If less
+        /// would be returned, the vault must fail with
+        /// [`crate::VaultStandardError::MinOutputNotMet`].
+        min_out: Option<Uint128>,
+        /// An optional swap route from the base token into `ask_asset`, in a
+        /// vault specific encoding. If not set, the vault picks the route.
+        route: Option<Binary>,
+        /// An optional field containing which address should receive the
+        /// returned tokens. If not set, the caller address will be used
+        /// instead.
+        recipient: Option<Recipient>,
+    },
+}
+
+impl ZapExecuteMsg {
+    /// Convert a [`ZapExecuteMsg`] into a [`CosmosMsg`].
+    pub fn into_cosmos_msg(self, contract_addr: String, funds: Vec<Coin>) -> StdResult<CosmosMsg> {
+        Ok(WasmMsg::Execute {
+            contract_addr,
+            msg: to_json_binary(&VaultStandardExecuteMsg::VaultExtension(
+                ExtensionExecuteMsg::Zap(self),
+            ))?,
+            funds,
+        }
+        .into())
+    }
+}
+
+/// Additional QueryMsg variants for vaults that enable the Zap extension.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum ZapQueryMsg {
+    /// Returns `Uint128` amount of vault tokens that would be minted by
+    /// calling `ZapExecuteMsg::ZapIn` with the same arguments in the same
+    /// transaction.
+    #[returns(Uint128)]
+    PreviewZapIn {
+        /// The token to deposit.
+        asset: TokenUnchecked,
+        /// The amount of `asset` to deposit.
+        amount: Uint128,
+        /// An optional swap route from `asset` into the base token.
+        route: Option<Binary>,
+    },
+
+    /// Returns `Uint128` amount of `ask_asset` that would be returned by
+    /// calling `ZapExecuteMsg::ZapOut` with the same arguments in the same
+    /// transaction.
+    #[returns(Uint128)]
+    PreviewZapOut {
+        /// The amount of vault tokens to redeem.
+        shares: Uint128,
+        /// The token to receive.
+        ask_asset: TokenUnchecked,
+        /// An optional swap route from the base token into `ask_asset`.
+        route: Option<Binary>,
+    },
+
+    /// Returns a `Vec<TokenUnchecked>` containing the tokens that can be used
+    /// with `ZapIn` and `ZapOut`. An empty list means that the vault accepts
+    /// any token it can find a route for.
+    #[returns(Vec<TokenUnchecked>)]
+    ZapAssets {},
+}
@@ -2,16 +2,22 @@ use std::marker::PhantomData;
 
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{
-    coin, to_json_binary, Addr, CosmosMsg, Decimal, Deps, QuerierWrapper, StdResult, Uint128,
-    WasmMsg,
+    coin, from_json, to_json_binary, Addr, Binary, CosmosMsg, Decimal, Deps, QuerierWrapper,
+    StdError, StdResult, Uint128, WasmMsg,
 };
 use schemars::JsonSchema;
+use serde::de::DeserializeOwned;
 use serde::Serialize;
 
 use crate::{
-    ExtensionExecuteMsg, ExtensionQueryMsg, VaultInfoResponse, VaultStandardExecuteMsg,
+    AssetAllocation, ConversionDirection, DepositCapStatusResponse, DetailedPreviewResponse,
+    ExtensionExecuteMsg, ExtensionQueryMsg, HistoricalPoint, MaxDepositResponse, MaxRedeemResponse,
+    OperationsStatusResponse, PositionValueResponse, Recipient, Rounding,
+    SupportsExtensionResponse, VaultInfoResponse, VaultStandardExecuteMsg,
     VaultStandardInfoResponse, VaultStandardQueryMsg,
 };
+#[cfg(feature = "multi-asset")]
+use crate::{Token, TokenUnchecked, VaultInfoExtendedResponse};
 
 /// A helper struct to interact with a vault contract that adheres to the vault
 /// standard. This struct contains an unchecked address. By calling the `check`
@@ -85,7 +91,7 @@ where
     pub fn deposit(
         &self,
         amount: impl Into<Uint128>,
-        recipient: Option<String>,
+        recipient: Option<Recipient>,
     ) -> StdResult<CosmosMsg> {
         let amount = amount.into();
 
@@ -94,6 +100,9 @@ where
             msg: to_json_binary(&VaultStandardExecuteMsg::<E>::Deposit {
                 amount: amount.clone(),
                 recipient,
+                callback: None,
+                min_shares_out: None,
+                deadline: None,
             })?,
             funds: vec![coin(amount.u128(), &self.base_token)],
         }
@@ -103,20 +112,81 @@ where
     /// Returns a CosmosMsg to deposit tokens into the vault, leaving the native
     /// funds field empty. This is useful for depositing cw20 tokens. The
     /// caller should have approved spend for the cw20 tokens first.
-    pub fn deposit_cw20(&self, amount: Uint128, recipient: Option<String>) -> StdResult<CosmosMsg> {
+    pub fn deposit_cw20(
+        &self,
+        amount: Uint128,
+        recipient: Option<Recipient>,
+    ) -> StdResult<CosmosMsg> {
         Ok(WasmMsg::Execute {
             contract_addr: self.addr.to_string(),
-            msg: to_json_binary(&VaultStandardExecuteMsg::<E>::Deposit { amount, recipient })?,
+            msg: to_json_binary(&VaultStandardExecuteMsg::<E>::Deposit {
+                amount,
+                recipient,
+                callback: None,
+                min_shares_out: None,
+                deadline: None,
+            })?,
             funds: vec![],
         }
         .into())
     }
 
+    /// Returns a CosmosMsg to deposit the entire base token balance of `owner`,
+    /// which should be the address that executes the returned message. Fails
+    /// if the balance is zero, since the bank module rejects zero amount
+    /// funds.
+    pub fn deposit_all(
+        &self,
+        querier: &QuerierWrapper,
+        owner: impl Into<String>,
+        recipient: Option<Recipient>,
+    ) -> StdResult<CosmosMsg> {
+        let balance = querier.query_balance(owner, &self.base_token)?;
+        if balance.amount.is_zero() {
+            return Err(StdError::generic_err("nothing to deposit"));
+        }
+        Ok(WasmMsg::Execute {
+            contract_addr: self.addr.to_string(),
+            msg: to_json_binary(&VaultStandardExecuteMsg::<E>::DepositAll { recipient })?,
+            funds: vec![balance],
+        }
+        .into())
+    }
+
+    /// Returns a CosmosMsg to deposit multiple tokens into the vault. Native
+    /// tokens in `assets` are sent along in the funds field. The caller should
+    /// have approved spend for any cw20 tokens first.
+    #[cfg(feature = "multi-asset")]
+    pub fn deposit_multiple(
+        &self,
+        assets: Vec<(Token, Uint128)>,
+        recipient: Option<Recipient>,
+    ) -> StdResult<CosmosMsg> {
+        let mut funds: Vec<_> = assets
+            .iter()
+            .filter_map(|(token, amount)| match token {
+                Token::Native(denom) => Some(coin(amount.u128(), denom)),
+                Token::Cw20(_) => None,
+            })
+            .collect();
+        funds.sort_by(|a, b| a.denom.cmp(&b.denom));
+
+        Ok(WasmMsg::Execute {
+            contract_addr: self.addr.to_string(),
+            msg: to_json_binary(&VaultStandardExecuteMsg::<E>::DepositMultiple {
+                assets: into_unchecked_assets(assets),
+                recipient,
+            })?,
+            funds,
+        }
+        .into())
+    }
+
     /// Returns a CosmosMsg to redeem vault tokens from the vault.
     pub fn redeem(
         &self,
         amount: impl Into<Uint128>,
-        recipient: Option<String>,
+        recipient: Option<Recipient>,
     ) -> StdResult<CosmosMsg> {
         let amount = amount.into();
         Ok(WasmMsg::Execute {
@@ -124,12 +194,77 @@ where
             msg: to_json_binary(&VaultStandardExecuteMsg::<E>::Redeem {
                 amount: amount.clone(),
                 recipient,
+                callback: None,
+                min_assets_out: None,
+                deadline: None,
             })?,
             funds: vec![coin(amount.u128(), &self.vault_token)],
         }
         .into())
     }
 
+    /// Returns a CosmosMsg to withdraw an exact amount of base tokens from the
+    /// vault. `max_vault_tokens` is the amount of vault tokens sent along with
+    /// the message, any excess of which is refunded by the vault.
+    pub fn withdraw(
+        &self,
+        amount: impl Into<Uint128>,
+        max_vault_tokens: impl Into<Uint128>,
+        recipient: Option<Recipient>,
+    ) -> StdResult<CosmosMsg> {
+        Ok(WasmMsg::Execute {
+            contract_addr: self.addr.to_string(),
+            msg: to_json_binary(&VaultStandardExecuteMsg::<E>::Withdraw {
+                amount: amount.into(),
+                recipient,
+            })?,
+            funds: vec![coin(max_vault_tokens.into().u128(), &self.vault_token)],
+        }
+        .into())
+    }
+
+    /// Returns a CosmosMsg to mint an exact amount of vault tokens.
+    /// `max_base_tokens` is the amount of base tokens sent along with the
+    /// message, any excess of which is refunded by the vault.
+    pub fn mint(
+        &self,
+        amount: impl Into<Uint128>,
+        max_base_tokens: impl Into<Uint128>,
+        recipient: Option<Recipient>,
+    ) -> StdResult<CosmosMsg> {
+        Ok(WasmMsg::Execute {
+            contract_addr: self.addr.to_string(),
+            msg: to_json_binary(&VaultStandardExecuteMsg::<E>::Mint {
+                amount: amount.into(),
+                recipient,
+            })?,
+            funds: vec![coin(max_base_tokens.into().u128(), &self.base_token)],
+        }
+        .into())
+    }
+
+    /// Returns a CosmosMsg to redeem the entire vault token balance of `owner`,
+    /// which should be the address that executes the returned message. Fails
+    /// if the balance is zero, since the bank module rejects zero amount
+    /// funds.
+    pub fn redeem_all(
+        &self,
+        querier: &QuerierWrapper,
+        owner: impl Into<String>,
+        recipient: Option<Recipient>,
+    ) -> StdResult<CosmosMsg> {
+        let balance = querier.query_balance(owner, &self.vault_token)?;
+        if balance.amount.is_zero() {
+            return Err(StdError::generic_err("nothing to redeem"));
+        }
+        Ok(WasmMsg::Execute {
+            contract_addr: self.addr.to_string(),
+            msg: to_json_binary(&VaultStandardExecuteMsg::<E>::RedeemAll { recipient })?,
+            funds: vec![balance],
+        }
+        .into())
+    }
+
     /// Queries the vault for the vault standard info
     pub fn query_vault_standard_info(
         &self,
@@ -146,6 +281,16 @@ where
         querier.query_wasm_smart(&self.addr, &VaultStandardQueryMsg::<Q>::Info {})
     }
 
+    /// Queries the vault for the extended vault info, supporting multiple base
+    /// tokens
+    #[cfg(feature = "multi-asset")]
+    pub fn query_vault_info_extended(
+        &self,
+        querier: &QuerierWrapper,
+    ) -> StdResult<VaultInfoExtendedResponse> {
+        querier.query_wasm_smart(&self.addr, &VaultStandardQueryMsg::<Q>::InfoExtended {})
+    }
+
     #[deprecated(
         since = "0.4.1",
         note = "PreviewDeposit and PreviewRedeem turned out to be too difficult to implement in most cases. We recommend to use transaction simulation from non-contract clients such as frontends."
@@ -182,6 +327,49 @@ where
         )
     }
 
+    /// Queries the vault for a preview of a withdraw
+    pub fn query_preview_withdraw(
+        &self,
+        querier: &QuerierWrapper,
+        amount: impl Into<Uint128>,
+    ) -> StdResult<Uint128> {
+        querier.query_wasm_smart(
+            &self.addr,
+            &VaultStandardQueryMsg::<Q>::PreviewWithdraw {
+                amount: amount.into(),
+            },
+        )
+    }
+
+    /// Queries the vault for a preview of a mint
+    pub fn query_preview_mint(
+        &self,
+        querier: &QuerierWrapper,
+        amount: impl Into<Uint128>,
+    ) -> StdResult<Uint128> {
+        querier.query_wasm_smart(
+            &self.addr,
+            &VaultStandardQueryMsg::<Q>::PreviewMint {
+                amount: amount.into(),
+            },
+        )
+    }
+
+    /// Queries the vault for a preview of a multi-asset deposit
+    #[cfg(feature = "multi-asset")]
+    pub fn query_preview_deposit_multiple(
+        &self,
+        querier: &QuerierWrapper,
+        assets: Vec<(Token, Uint128)>,
+    ) -> StdResult<Uint128> {
+        querier.query_wasm_smart(
+            &self.addr,
+            &VaultStandardQueryMsg::<Q>::PreviewDepositMultiple {
+                assets: into_unchecked_assets(assets),
+            },
+        )
+    }
+
     /// Queries the vault for the total assets held in the vault
     pub fn query_total_assets(&self, querier: &QuerierWrapper) -> StdResult<Uint128> {
         querier.query_wasm_smart(&self.addr, &VaultStandardQueryMsg::<Q>::TotalAssets {})
@@ -195,10 +383,11 @@ where
         )
     }
 
-    /// Queries the vault for the vault token exchange rate
+    /// Queries the vault for the vault token exchange rate. If `quote_denom`
+    /// is `None`, the rate is quoted in the base token of the vault.
     pub fn query_vault_token_exchange_rate(
         &self,
-        quote_denom: String,
+        quote_denom: Option<String>,
         querier: &QuerierWrapper,
     ) -> StdResult<Decimal> {
         querier.query_wasm_smart(
@@ -212,11 +401,13 @@ where
         &self,
         querier: &QuerierWrapper,
         amount: impl Into<Uint128>,
+        rounding: Option<Rounding>,
     ) -> StdResult<Uint128> {
         querier.query_wasm_smart(
             &self.addr,
             &VaultStandardQueryMsg::<Q>::ConvertToShares {
                 amount: amount.into(),
+                rounding,
             },
         )
     }
@@ -226,12 +417,199 @@ where
         &self,
         querier: &QuerierWrapper,
         amount: impl Into<Uint128>,
+        rounding: Option<Rounding>,
     ) -> StdResult<Uint128> {
         querier.query_wasm_smart(
             &self.addr,
             &VaultStandardQueryMsg::<Q>::ConvertToAssets {
                 amount: amount.into(),
+                rounding,
             },
         )
     }
+
+    /// Queries the vault to convert an amount of tokens using the exchange rate
+    /// at a past block height or time
+    pub fn query_historical_convert(
+        &self,
+        querier: &QuerierWrapper,
+        amount: impl Into<Uint128>,
+        direction: ConversionDirection,
+        at: HistoricalPoint,
+    ) -> StdResult<Uint128> {
+        querier.query_wasm_smart(
+            &self.addr,
+            &VaultStandardQueryMsg::<Q>::HistoricalConvert {
+                amount: amount.into(),
+                direction,
+                at,
+            },
+        )
+    }
+
+    /// Queries the vault for the maximum amount of base tokens that `owner`
+    /// can currently withdraw
+    pub fn query_max_withdraw(
+        &self,
+        querier: &QuerierWrapper,
+        owner: impl Into<String>,
+    ) -> StdResult<Uint128> {
+        querier.query_wasm_smart(
+            &self.addr,
+            &VaultStandardQueryMsg::<Q>::MaxWithdraw {
+                owner: owner.into(),
+            },
+        )
+    }
+
+    /// Queries the vault for the maximum amount of vault tokens that `owner`
+    /// can currently redeem, and the base tokens they are worth
+    pub fn query_max_redeem(
+        &self,
+        querier: &QuerierWrapper,
+        owner: impl Into<String>,
+    ) -> StdResult<MaxRedeemResponse> {
+        querier.query_wasm_smart(
+            &self.addr,
+            &VaultStandardQueryMsg::<Q>::MaxRedeem {
+                owner: owner.into(),
+            },
+        )
+    }
+
+    /// Queries the vault for the limits on depositing for `recipient`
+    pub fn query_max_deposit(
+        &self,
+        querier: &QuerierWrapper,
+        recipient: impl Into<String>,
+    ) -> StdResult<MaxDepositResponse> {
+        querier.query_wasm_smart(
+            &self.addr,
+            &VaultStandardQueryMsg::<Q>::MaxDeposit {
+                recipient: recipient.into(),
+            },
+        )
+    }
+
+    /// Queries the vault for which of its operations are currently enabled
+    pub fn query_operations_status(
+        &self,
+        querier: &QuerierWrapper,
+    ) -> StdResult<OperationsStatusResponse> {
+        querier.query_wasm_smart(&self.addr, &VaultStandardQueryMsg::<Q>::OperationsStatus {})
+    }
+
+    /// Queries the vault for the status of its deposit cap
+    pub fn query_deposit_cap_status(
+        &self,
+        querier: &QuerierWrapper,
+    ) -> StdResult<DepositCapStatusResponse> {
+        querier.query_wasm_smart(&self.addr, &VaultStandardQueryMsg::<Q>::DepositCapStatus {})
+    }
+
+    /// Queries the vault for the vault token balance of `owner` and its value
+    /// in base tokens
+    pub fn query_position_value(
+        &self,
+        querier: &QuerierWrapper,
+        owner: impl Into<String>,
+    ) -> StdResult<PositionValueResponse> {
+        querier.query_wasm_smart(
+            &self.addr,
+            &VaultStandardQueryMsg::<Q>::PositionValue {
+                owner: owner.into(),
+            },
+        )
+    }
+
+    /// Queries the vault for a breakdown of where its assets are held
+    pub fn query_total_assets_breakdown(
+        &self,
+        querier: &QuerierWrapper,
+    ) -> StdResult<Vec<AssetAllocation>> {
+        querier.query_wasm_smart(
+            &self.addr,
+            &VaultStandardQueryMsg::<Q>::TotalAssetsBreakdown {},
+        )
+    }
+
+    /// Queries the vault for whether it supports the extension with the given
+    /// name
+    pub fn query_supports_extension(
+        &self,
+        querier: &QuerierWrapper,
+        name: impl Into<String>,
+    ) -> StdResult<SupportsExtensionResponse> {
+        querier.query_wasm_smart(
+            &self.addr,
+            &VaultStandardQueryMsg::<Q>::SupportsExtension { name: name.into() },
+        )
+    }
+
+    /// Queries the vault for a preview of a deposit including a breakdown of
+    /// the fees charged
+    pub fn query_preview_deposit_detailed(
+        &self,
+        querier: &QuerierWrapper,
+        amount: impl Into<Uint128>,
+    ) -> StdResult<DetailedPreviewResponse> {
+        querier.query_wasm_smart(
+            &self.addr,
+            &VaultStandardQueryMsg::<Q>::PreviewDepositDetailed {
+                amount: amount.into(),
+            },
+        )
+    }
+
+    /// Queries the vault for a preview of a redeem including a breakdown of
+    /// the fees charged
+    pub fn query_preview_redeem_detailed(
+        &self,
+        querier: &QuerierWrapper,
+        amount: impl Into<Uint128>,
+    ) -> StdResult<DetailedPreviewResponse> {
+        querier.query_wasm_smart(
+            &self.addr,
+            &VaultStandardQueryMsg::<Q>::PreviewRedeemDetailed {
+                amount: amount.into(),
+            },
+        )
+    }
+
+    /// Queries the vault with a batch of queries, returning the JSON encoded
+    /// responses in the same order as the queries. Use
+    /// [`decode_batch_response`] to decode the individual responses.
+    pub fn query_batch(
+        &self,
+        querier: &QuerierWrapper,
+        queries: Vec<VaultStandardQueryMsg<Q>>,
+    ) -> StdResult<Vec<Binary>> {
+        querier.query_wasm_smart(&self.addr, &VaultStandardQueryMsg::<Q>::Batch { queries })
+    }
+}
+
+/// Decodes the response at `index` of a `VaultStandardQueryMsg::Batch` query
+/// into its typed value.
+pub fn decode_batch_response<R: DeserializeOwned>(
+    responses: &[Binary],
+    index: usize,
+) -> StdResult<R> {
+    let response = responses.get(index).ok_or_else(|| {
+        StdError::generic_err(format!(
+            "batch response index {} out of bounds, got {} responses",
+            index,
+            responses.len()
+        ))
+    })?;
+    from_json(response)
+}
+
+/// Converts a list of tokens and amounts into the unchecked form used in the
+/// multi-asset messages.
+#[cfg(feature = "multi-asset")]
+fn into_unchecked_assets(assets: Vec<(Token, Uint128)>) -> Vec<(TokenUnchecked, Uint128)> {
+    assets
+        .into_iter()
+        .map(|(token, amount)| (token.into(), amount))
+        .collect()
 }
@@ -67,6 +67,37 @@
 //! * [Lockup](crate::extensions::lockup)
 //! * [ForceUnlock](crate::extensions::force_unlock)
 //! * [Keeper](crate::extensions::keeper)
+//! * [LockupNft](crate::extensions::lockup_nft)
+//! * [InstantUnlock](crate::extensions::instant_unlock)
+//! * [Fee](crate::extensions::fee)
+//! * [Admin](crate::extensions::admin)
+//! * [Pause](crate::extensions::pause)
+//! * [Whitelist](crate::extensions::whitelist)
+//! * [Caps](crate::extensions::caps)
+//! * [Autocompound](crate::extensions::autocompound)
+//! * [Rewards](crate::extensions::rewards)
+//! * [Zap](crate::extensions::zap)
+//! * [Oracle](crate::extensions::oracle)
+//! * [Strategies](crate::extensions::strategies)
+//! * [AsyncRedeem](crate::extensions::async_redeem)
+//! * [RateLimit](crate::extensions::rate_limit)
+//! * [Referral](crate::extensions::referral)
+//! * [Vesting](crate::extensions::vesting)
+//! * [Migration](crate::extensions::migration)
+//! * [Snapshot](crate::extensions::snapshot)
+//! * [VotingPower](crate::extensions::voting_power)
+//! * [Insurance](crate::extensions::insurance)
+//! * [EmergencyWithdraw](crate::extensions::emergency_withdraw)
+//! * [PerformanceFee](crate::extensions::performance_fee)
+//! * [Operator](crate::extensions::operator)
+//! * [Permit](crate::extensions::permit)
+//! * [Leverage](crate::extensions::leverage)
+//! * [Compliance](crate::extensions::compliance)
+//! * [Timelock](crate::extensions::timelock)
+//! * [Metrics](crate::extensions::metrics)
+//! * [Accounting](crate::extensions::accounting)
+//! * [Schedule](crate::extensions::schedule)
+//! * [Ica](crate::extensions::ica)
 //! * [Cw4626](crate::extensions::cw4626)
 //!
 //! Each of these extensions are available in this repo via cargo features. To
@@ -99,6 +130,189 @@
 //! addresses or anyone to act as a "keeper" for the vault and call functions to
 //! perform jobs that need to be done to keep the vault running.
 //!
+//! ### LockupNft
+//! The lockup NFT extension can be used together with the `Lockup` extension to
+//! let users wrap their lockup positions into cw721 tokens, making the positions
+//! tradable and usable as collateral in NFT aware protocols.
+//!
+//! ### InstantUnlock
+//! The instant unlock extension can be used together with the `Lockup` extension
+//! by vaults that can source exit liquidity, e.g. via a secondary market or a
+//! penalty pool. It lets users preview and execute an immediate exit from a
+//! lockup position in exchange for a haircut on the returned base tokens.
+//!
+//! ### Fee
+//! The fee extension can be used by vaults that charge fees. It lets integrators
+//! such as aggregators discover the deposit, withdrawal, management and
+//! performance fees of the vault in a uniform way, e.g. to compute the net APY,
+//! and lets the admin update the fees within fixed bounds.
+//!
+//! ### Admin
+//! The admin extension lets tooling discover who controls the vault, and lets the
+//! owner transfer ownership in two steps or renounce it, following the semantics
+//! of the cw-ownable crate.
+//!
+//! ### Pause
+//! The pause extension adds a circuit breaker to the vault. The admin or a
+//! guardian can pause deposits, redemptions and unlocks individually during an
+//! incident, and integrators can query which operations are paused. While an
+//! operation is paused the corresponding `MaxDeposit`, `MaxWithdraw` and
+//! `MaxRedeem` queries return zero.
+//!
+//! ### Whitelist
+//! The whitelist extension can be used by permissioned vaults, e.g. private or
+//! institutional vaults, that only accept deposits from a set of allowed
+//! addresses. It lets the admin manage the whitelist and lets integrators check
+//! whether an address may deposit, without changing the standard messages.
+//!
+//! ### Caps
+//! The caps extension can be used by vaults that limit deposits, e.g. during a
+//! guarded launch. It exposes the global cap and the caps of individual
+//! addresses and lets the admin update them. The caps are reflected by the
+//! standard `MaxDeposit` query, so frontends can show when the vault is full.
+//!
+//! ### Autocompound
+//! The autocompound extension can be used by vaults that harvest rewards and
+//! reinvest them into the base token. It provides a standard `Compound` trigger,
+//! telemetry about the last compound, and the compound fee configuration, so
+//! that yield aggregators and keepers can handle different vaults uniformly.
+//!
+//! ### Rewards
+//! The rewards extension can be used by vaults that distribute reward tokens,
+//! e.g. external incentives, to the holders of their vault tokens instead of
+//! compounding them. It provides a standard interface for claiming rewards and
+//! querying the pending rewards of an address.
+//!
+//! ### Zap
+//! The zap extension can be used by vaults that accept deposits and return
+//! withdrawals in tokens other than the base token. The vault handles swapping
+//! between the given token and the base token, so that routers can deposit any
+//! token in a single message, e.g. into an LP vault.
+//!
+//! ### Oracle
+//! The oracle extension exposes a time weighted average of the share price of
+//! the vault, along with the raw accumulator it is computed from. Using the spot
+//! exchange rate to value vault tokens as collateral is prone to manipulation,
+//! while the average can not be moved within a single block.
+//!
+//! ### Strategies
+//! The strategies extension can be used by meta-vaults that allocate their funds
+//! across multiple sub-strategies, e.g. other vaults or lending markets. It
+//! exposes the strategies with their target weights and deployed amounts, and
+//! lets the admin add, remove and rebalance them.
+//!
+//! ### AsyncRedeem
+//! The async redeem extension can be used by vaults backed by illiquid positions,
+//! e.g. real world assets or unbonding stake, that can not fulfill `Redeem`
+//! synchronously. Modeled on ERC-7540, users request a redemption, the vault
+//! fulfills it asynchronously and the user then claims the base tokens.
+//!
+//! ### RateLimit
+//! The rate limit extension can be used by vaults that limit the amount of base
+//! tokens deposited or withdrawn per epoch, e.g. bridged or risk managed vaults.
+//! The remaining quota is reflected by the `MaxDeposit`, `MaxWithdraw` and
+//! `MaxRedeem` queries, so integrators can anticipate the limits.
+//!
+//! ### Referral
+//! The referral extension lets deposits be attributed to a referrer, e.g. the
+//! frontend that routed the deposit, who earns a share of the fees charged by
+//! the vault. It provides a deposit message carrying the referrer, queries for
+//! referral earnings and a message to claim them.
+//!
+//! ### Vesting
+//! The vesting extension can be used by vaults where claimed incentives vest
+//! linearly over time instead of being paid out immediately. It exposes the
+//! vesting positions of an owner and the vesting schedule, and lets owners claim
+//! the vested amounts.
+//!
+//! ### Migration
+//! The migration extension lets users move their position into another vault
+//! with the same base token in a single transaction, e.g. after a protocol
+//! upgrade. The vault redeems the vault tokens and deposits the base tokens into
+//! an approved destination vault, without the user having to exit and re-enter.
+//!
+//! ### Snapshot
+//! The snapshot extension lets the admin or keepers record the vault token
+//! balances of all owners at a point in time. Airdrops and fee rebates can then
+//! be computed against historical balances without off-chain indexing.
+//!
+//! ### VotingPower
+//! The voting power extension exposes governance voting power derived from the
+//! vault tokens of an owner, optionally boosted by lockups, at past block
+//! heights. The responses match those of DAO DAO voting modules, so that vault
+//! tokens can be used directly as governance weight.
+//!
+//! ### Insurance
+//! The insurance extension can be used by vaults with an attached insurance fund
+//! that covers losses of depositors. It exposes the balance and coverage ratio
+//! of the fund, and a process for filing claims and querying their status.
+//!
+//! ### EmergencyWithdraw
+//! The emergency withdraw extension provides a standard escape hatch for users
+//! during incidents. It redeems vault tokens against the idle reserves of the
+//! vault only, bypassing its strategies, and remains available even when the
+//! normal redemption path is paused or frozen.
+//!
+//! ### PerformanceFee
+//! The performance fee extension can be used by vaults that charge a performance
+//! fee on yield above a high-watermark share price. It exposes the
+//! high-watermark, the crystallization schedule and the accrued fees, so that
+//! net-of-fee yields can be compared across vaults, and a message for keepers to
+//! crystallize the fees.
+//!
+//! ### Operator
+//! The operator extension lets owners of vault tokens approve operators, e.g.
+//! credit managers or routers, that can redeem and unlock on their behalf
+//! without holding the vault tokens custodially. It mirrors the operator
+//! semantics of ERC-7540.
+//!
+//! ### Permit
+//! The permit extension lets users authorize deposits and redemptions with an
+//! off-chain secp256k1 signature over a canonical sign doc, which a relayer can
+//! then submit on their behalf. Permits are bound to a chain, vault, nonce and
+//! expiry to prevent replays.
+//!
+//! ### Leverage
+//! The leverage extension can be used by vaults that let users borrow base tokens
+//! against their vault tokens, e.g. leveraged yield vaults. It provides messages
+//! for borrowing, repaying and liquidating, and queries for positions with their
+//! loan to value and the liquidation parameters of the vault.
+//!
+//! ### Compliance
+//! The compliance extension can be used by vaults, e.g. real world asset vaults,
+//! that only accept deposits from addresses holding an attested credential. The
+//! vault checks depositors against a compliance checker contract and rejects
+//! non-compliant deposits with a standard error.
+//!
+//! ### Timelock
+//! The timelock extension requires sensitive admin actions, such as fee changes
+//! or strategy swaps, to be proposed and queued for a delay before they can be
+//! executed. Depositors and risk monitors can query the pending actions and react
+//! before they take effect.
+//!
+//! ### Metrics
+//! The metrics extension exposes the APR, APY and performance history of the
+//! vault, as reported by the vault itself. This lets aggregator frontends display
+//! yields without scraping events and recomputing them inconsistently. The
+//! values are self-reported and not verified by the standard.
+//!
+//! ### Accounting
+//! The accounting extension makes the vault record periodic share price
+//! checkpoints on-chain. This allows performance fees to be verified and disputes
+//! to be resolved against historical share prices, without relying on off-chain
+//! indexers.
+//!
+//! ### Schedule
+//! The schedule extension lets users pre-fund a schedule of recurring deposits,
+//! e.g. for dollar cost averaging into the vault. The deposits are executed by
+//! keepers once they are due, without the need for a separate wrapper contract.
+//!
+//! ### Ica
+//! The ICA extension can be used by vaults that deploy their base tokens into
+//! strategies on other chains through interchain accounts. It defines the
+//! messages for depositing and redeeming remotely, the acknowledgement messages
+//! that settle these operations, and queries for the pending operations.
+//!
 //! ### Cw4626
 //! The Cw4626 extension is the only extension provided with in this repo that
 //! does not extend the default [`VaultStandardExecuteMsg`] and
@@ -124,8 +338,43 @@ pub mod msg;
 /// Module containing a helper struct for interacting with a vault contract.
 pub mod helper;
 
+/// Module containing the `Token` type used to identify native and cw20 tokens.
+pub mod token;
+
+/// Module containing conversions between `Token` and the types of the
+/// [cw-asset](https://docs.rs/cw-asset) crate.
+#[cfg(feature = "asset")]
+#[cfg_attr(docsrs, doc(cfg(feature = "asset")))]
+pub mod asset;
+
+/// Module containing conversions between `Token` and the asset types of
+/// [Astroport](https://docs.rs/astroport).
+#[cfg(feature = "astroport")]
+#[cfg_attr(docsrs, doc(cfg(feature = "astroport")))]
+pub mod astroport;
+
+/// Module containing helpers for managing a native vault token through the
+/// TokenFactory module.
+#[cfg(feature = "tokenfactory")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tokenfactory")))]
+pub mod tokenfactory;
+
+/// Module containing adapters for registering keeper jobs with external
+/// automation networks such as [CronCat](https://cron.cat).
+#[cfg(feature = "automation")]
+#[cfg_attr(docsrs, doc(cfg(feature = "automation")))]
+pub mod automation;
+
+/// Module containing the standard error type returned by vaults.
+pub mod error;
+/// Module containing helper functions for vaults to validate the conditions
+/// specified on the standard messages.
+pub mod validation;
+
+pub use error::*;
 pub use helper::*;
 pub use msg::*;
+pub use token::*;
 
 /// The version of the vault standard.
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
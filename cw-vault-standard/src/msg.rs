@@ -1,13 +1,94 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+#[cfg(feature = "accounting")]
+use crate::extensions::accounting::{AccountingExecuteMsg, AccountingQueryMsg};
+#[cfg(feature = "admin")]
+use crate::extensions::admin::{AdminExecuteMsg, AdminQueryMsg};
+#[cfg(feature = "async-redeem")]
+use crate::extensions::async_redeem::{AsyncRedeemExecuteMsg, AsyncRedeemQueryMsg};
+#[cfg(feature = "autocompound")]
+use crate::extensions::autocompound::{AutocompoundExecuteMsg, AutocompoundQueryMsg};
+#[cfg(feature = "caps")]
+use crate::extensions::caps::{CapsExecuteMsg, CapsQueryMsg};
+#[cfg(feature = "compliance")]
+use crate::extensions::compliance::{ComplianceExecuteMsg, ComplianceQueryMsg};
+#[cfg(feature = "emergency-withdraw")]
+use crate::extensions::emergency_withdraw::{
+    EmergencyWithdrawExecuteMsg, EmergencyWithdrawQueryMsg,
+};
+#[cfg(feature = "fee")]
+use crate::extensions::fee::{FeeExecuteMsg, FeeQueryMsg};
 #[cfg(feature = "force-unlock")]
-use crate::extensions::force_unlock::ForceUnlockExecuteMsg;
+use crate::extensions::force_unlock::{ForceUnlockExecuteMsg, ForceUnlockQueryMsg};
+#[cfg(feature = "ica")]
+use crate::extensions::ica::{IcaExecuteMsg, IcaQueryMsg};
+#[cfg(feature = "instant-unlock")]
+use crate::extensions::instant_unlock::{InstantUnlockExecuteMsg, InstantUnlockQueryMsg};
+#[cfg(feature = "insurance")]
+use crate::extensions::insurance::{InsuranceExecuteMsg, InsuranceQueryMsg};
 #[cfg(feature = "keeper")]
 use crate::extensions::keeper::{KeeperExecuteMsg, KeeperQueryMsg};
+#[cfg(feature = "leverage")]
+use crate::extensions::leverage::{LeverageExecuteMsg, LeverageQueryMsg};
 #[cfg(feature = "lockup")]
 use crate::extensions::lockup::{LockupExecuteMsg, LockupQueryMsg};
+#[cfg(feature = "lockup-nft")]
+use crate::extensions::lockup_nft::{LockupNftExecuteMsg, LockupNftQueryMsg};
+#[cfg(feature = "metrics")]
+use crate::extensions::metrics::MetricsQueryMsg;
+#[cfg(feature = "migration")]
+use crate::extensions::migration::{MigrationExecuteMsg, MigrationQueryMsg};
+#[cfg(feature = "operator")]
+use crate::extensions::operator::{OperatorExecuteMsg, OperatorQueryMsg};
+#[cfg(feature = "oracle")]
+use crate::extensions::oracle::OracleQueryMsg;
+#[cfg(feature = "pause")]
+use crate::extensions::pause::{PauseExecuteMsg, PauseQueryMsg};
+#[cfg(feature = "performance-fee")]
+use crate::extensions::performance_fee::{PerformanceFeeExecuteMsg, PerformanceFeeQueryMsg};
+#[cfg(feature = "permit")]
+use crate::extensions::permit::{PermitExecuteMsg, PermitQueryMsg};
+#[cfg(feature = "rate-limit")]
+use crate::extensions::rate_limit::{RateLimitExecuteMsg, RateLimitQueryMsg};
+#[cfg(feature = "referral")]
+use crate::extensions::referral::{ReferralExecuteMsg, ReferralQueryMsg};
+#[cfg(feature = "rewards")]
+use crate::extensions::rewards::{RewardsExecuteMsg, RewardsQueryMsg};
+#[cfg(feature = "schedule")]
+use crate::extensions::schedule::{ScheduleExecuteMsg, ScheduleQueryMsg};
+#[cfg(feature = "snapshot")]
+use crate::extensions::snapshot::{SnapshotExecuteMsg, SnapshotQueryMsg};
+#[cfg(feature = "strategies")]
+use crate::extensions::strategies::{StrategiesExecuteMsg, StrategiesQueryMsg};
+#[cfg(feature = "timelock")]
+use crate::extensions::timelock::{TimelockExecuteMsg, TimelockQueryMsg};
+#[cfg(feature = "vesting")]
+use crate::extensions::vesting::{VestingExecuteMsg, VestingQueryMsg};
+#[cfg(feature = "voting-power")]
+use crate::extensions::voting_power::VotingPowerQueryMsg;
+#[cfg(feature = "whitelist")]
+use crate::extensions::whitelist::{WhitelistExecuteMsg, WhitelistQueryMsg};
+#[cfg(feature = "zap")]
+use crate::extensions::zap::{ZapExecuteMsg, ZapQueryMsg};
+#[cfg(feature = "multi-asset")]
+use crate::TokenUnchecked;
+use crate::{Token, TokenAmount};
+#[cfg(feature = "multi-asset")]
+use cosmwasm_std::{Decimal, StdError};
 
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{to_json_binary, Coin, CosmosMsg, Empty, StdResult, Uint128, WasmMsg};
-use schemars::JsonSchema;
+use cosmwasm_std::{
+    to_json_binary, BankMsg, Binary, Coin, CosmosMsg, QuerierWrapper, StdResult, Timestamp,
+    Uint128, WasmMsg,
+};
+use schemars::gen::SchemaGenerator;
+use schemars::schema::{RootSchema, Schema};
+use schemars::{schema_for, JsonSchema};
+use serde::de::value::MapAccessDeserializer;
+use serde::de::{self, MapAccess, Visitor};
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 /// The default ExecuteMsg variants that all vaults must implement.
 /// This enum can be extended with additional variants by defining an extension
@@ -25,8 +106,21 @@ pub enum VaultStandardExecuteMsg<T = ExtensionExecuteMsg> {
         )]
         amount: Uint128,
         /// The optional recipient of the vault token. If not set, the caller
-        /// address will be used instead.
-        recipient: Option<String>,
+        /// address will be used instead. May carry a message to execute on
+        /// the recipient contract, see [`Recipient`].
+        recipient: Option<Recipient>,
+        /// If set, the vault must dispatch a [`VaultCallbackMsg::AfterDeposit`]
+        /// to the caller after the deposit, with its `data` field set to this
+        /// value.
+        callback: Option<Binary>,
+        /// The minimum amount of vault tokens that must be minted. If fewer
+        /// vault tokens would be minted, the vault must fail with
+        /// [`crate::VaultStandardError::MinOutputNotMet`].
+        min_shares_out: Option<Uint128>,
+        /// An optional deadline for the deposit. If the block time is after
+        /// the deadline, the vault must fail with
+        /// [`crate::VaultStandardError::DeadlineExceeded`].
+        deadline: Option<Timestamp>,
     },
 
     /// Called to redeem vault tokens and receive assets back from the vault.
@@ -36,8 +130,9 @@ pub enum VaultStandardExecuteMsg<T = ExtensionExecuteMsg> {
     Redeem {
         /// An optional field containing which address should receive the
         /// withdrawn base tokens. If not set, the caller address will be
-        /// used instead.
-        recipient: Option<String>,
+        /// used instead. May carry a message to execute on the recipient
+        /// contract, see [`Recipient`].
+        recipient: Option<Recipient>,
         /// The amount of vault tokens sent to the contract. In the case that
         /// the vault token is a Cosmos native denom, we of course have this
         /// information in info.funds, but if the vault implements the
@@ -50,6 +145,104 @@ pub enum VaultStandardExecuteMsg<T = ExtensionExecuteMsg> {
             of vault tokens should instead be read from the actual amount of sent vault tokens."
         )]
         amount: Uint128,
+        /// If set, the vault must dispatch a [`VaultCallbackMsg::AfterRedeem`]
+        /// to the caller after the redemption, with its `data` field set to
+        /// this value.
+        callback: Option<Binary>,
+        /// The minimum amount of base tokens that must be returned. If fewer
+        /// base tokens would be returned, the vault must fail with
+        /// [`crate::VaultStandardError::MinOutputNotMet`].
+        min_assets_out: Option<Uint128>,
+        /// An optional deadline for the redemption. If the block time is after
+        /// the deadline, the vault must fail with
+        /// [`crate::VaultStandardError::DeadlineExceeded`].
+        deadline: Option<Timestamp>,
+    },
+
+    /// Called to withdraw an exact amount of base tokens from the vault. This
+    /// is the base token denominated counterpart of `Redeem`.
+    ///
+    /// The native vault token must be passed in the funds parameter. The vault
+    /// must burn the amount of vault tokens returned by
+    /// `QueryMsg::PreviewWithdraw`, rounded up in favor of the vault, and
+    /// refund any excess vault tokens to the caller. Must fail if the sent
+    /// vault tokens are not enough to withdraw `amount` base tokens.
+    Withdraw {
+        /// The exact amount of base tokens to withdraw.
+        amount: Uint128,
+        /// An optional field containing which address should receive the
+        /// withdrawn base tokens. If not set, the caller address will be
+        /// used instead. May carry a message to execute on the recipient
+        /// contract, see [`Recipient`].
+        recipient: Option<Recipient>,
+    },
+
+    /// Called to mint an exact amount of vault tokens by depositing however
+    /// many base tokens are required. This is the vault token denominated
+    /// counterpart of `Deposit`.
+    ///
+    /// Native base tokens are passed in the funds parameter. The vault must
+    /// deposit the amount of base tokens returned by `QueryMsg::PreviewMint`,
+    /// rounded up in favor of the vault, and refund any excess base tokens to
+    /// the caller. Must fail if the sent base tokens are not enough to mint
+    /// `amount` vault tokens.
+    Mint {
+        /// The exact amount of vault tokens to mint.
+        amount: Uint128,
+        /// The optional recipient of the vault tokens. If not set, the caller
+        /// address will be used instead. May carry a message to execute on
+        /// the recipient contract, see [`Recipient`]. Any refunded base
+        /// tokens are always sent to the caller.
+        recipient: Option<Recipient>,
+    },
+
+    /// Called to deposit all of the base tokens sent to the vault, without
+    /// having to specify an amount. Native base tokens must be passed in the
+    /// funds parameter, and the vault must deposit the entire amount of base
+    /// tokens sent.
+    ///
+    /// Avoids the mismatch between the `amount` field of `Deposit` and the
+    /// actually sent funds. Vaults can use
+    /// [`crate::validation::assert_deposit_funds`] to read the deposited
+    /// amount for both `Deposit` and `DepositAll`.
+    DepositAll {
+        /// The optional recipient of the vault token. If not set, the caller
+        /// address will be used instead. May carry a message to execute on
+        /// the recipient contract, see [`Recipient`].
+        recipient: Option<Recipient>,
+    },
+
+    /// Called to deposit multiple tokens into the vault at once, for vaults
+    /// whose underlying position consists of several tokens, such as an LP
+    /// position or a basket of tokens.
+    ///
+    /// Native tokens are passed in the funds parameter, and the vault must
+    /// fail if the sent funds do not match `assets`. Cw20 tokens must have
+    /// been approved for the vault to spend beforehand.
+    #[cfg(feature = "multi-asset")]
+    DepositMultiple {
+        /// The tokens and amounts to deposit.
+        assets: Vec<(TokenUnchecked, Uint128)>,
+        /// The optional recipient of the vault token. If not set, the caller
+        /// address will be used instead. May carry a message to execute on
+        /// the recipient contract, see [`Recipient`].
+        recipient: Option<Recipient>,
+    },
+
+    /// Called to redeem all of the vault tokens sent to the vault, without
+    /// having to specify an amount. The native vault token must be passed in
+    /// the funds parameter, and the vault must redeem the entire amount sent.
+    ///
+    /// Useful for contracts that hold vault tokens and want to fully exit,
+    /// since the amount is read from the sent funds and can therefore not go
+    /// stale. See `VaultContract::redeem_all` for a helper that sends the full
+    /// vault token balance of an address.
+    RedeemAll {
+        /// An optional field containing which address should receive the
+        /// withdrawn base tokens. If not set, the caller address will be
+        /// used instead. May carry a message to execute on the recipient
+        /// contract, see [`Recipient`].
+        recipient: Option<Recipient>,
     },
 
     /// Called to execute functionality of any enabled extensions.
@@ -68,6 +261,206 @@ impl VaultStandardExecuteMsg {
     }
 }
 
+/// The recipient of tokens delivered by the vault, e.g. the vault tokens minted
+/// on `Deposit` or the base tokens returned on `Redeem`.
+///
+/// If `msg` is set, the vault must deliver the tokens by executing `msg` on the
+/// recipient contract with the tokens attached as funds, instead of a plain
+/// bank send. This allows composable flows such as zaps and routers, without
+/// any custom per vault integrations.
+///
+/// A recipient without a message is serialized as a plain address string, so
+/// that the `recipient` field stays compatible with vaults and callers using a
+/// plain address. A recipient with a message is serialized as
+/// `{"addr": "...", "msg": "..."}`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Recipient {
+    /// The address of the recipient.
+    pub addr: String,
+    /// The optional JSON encoded ExecuteMsg to execute on the recipient
+    /// contract when delivering the tokens.
+    pub msg: Option<Binary>,
+}
+
+impl Recipient {
+    /// Create a new Recipient instance, executing `msg` on the recipient
+    /// contract when delivering the tokens.
+    pub fn with_msg(addr: impl Into<String>, msg: Binary) -> Self {
+        Self {
+            addr: addr.into(),
+            msg: Some(msg),
+        }
+    }
+
+    /// Returns the address of the recipient.
+    pub fn addr(&self) -> &str {
+        &self.addr
+    }
+
+    /// Returns a [`CosmosMsg`] delivering the native `funds` to the recipient.
+    /// This is a `BankMsg::Send` for a plain address, and a `WasmMsg::Execute`
+    /// with the funds attached if the recipient carries a message.
+    pub fn into_send_msg(self, funds: Vec<Coin>) -> CosmosMsg {
+        match self.msg {
+            None => BankMsg::Send {
+                to_address: self.addr,
+                amount: funds,
+            }
+            .into(),
+            Some(msg) => WasmMsg::Execute {
+                contract_addr: self.addr,
+                msg,
+                funds,
+            }
+            .into(),
+        }
+    }
+}
+
+impl From<String> for Recipient {
+    fn from(addr: String) -> Self {
+        Self { addr, msg: None }
+    }
+}
+
+impl From<&str> for Recipient {
+    fn from(addr: &str) -> Self {
+        Self::from(addr.to_string())
+    }
+}
+
+impl Serialize for Recipient {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match &self.msg {
+            None => serializer.serialize_str(&self.addr),
+            Some(msg) => {
+                let mut state = serializer.serialize_struct("Recipient", 2)?;
+                state.serialize_field("addr", &self.addr)?;
+                state.serialize_field("msg", msg)?;
+                state.end()
+            }
+        }
+    }
+}
+
+/// Implemented with a visitor instead of an untagged enum, like the
+/// `Deserialize` implementation of [`crate::Token`].
+impl<'de> Deserialize<'de> for Recipient {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(RecipientVisitor)
+    }
+}
+
+struct RecipientVisitor;
+
+impl<'de> Visitor<'de> for RecipientVisitor {
+    type Value = Recipient;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("an address or an object with an `addr` and an optional `msg`")
+    }
+
+    fn visit_str<E: de::Error>(self, addr: &str) -> Result<Recipient, E> {
+        Ok(Recipient::from(addr))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Recipient, A::Error> {
+        #[derive(Deserialize)]
+        #[serde(deny_unknown_fields)]
+        struct Object {
+            addr: String,
+            msg: Option<Binary>,
+        }
+
+        let Object { addr, msg } = Object::deserialize(MapAccessDeserializer::new(map))?;
+        Ok(Recipient { addr, msg })
+    }
+}
+
+// The JSON schema of `Recipient`, matching its `Serialize` implementation.
+/// The recipient of tokens delivered by the vault, either a plain address or
+/// an address along with a message to execute on it.
+#[derive(JsonSchema)]
+#[serde(untagged)]
+#[allow(dead_code)]
+enum RecipientSchema {
+    /// A plain address to send the tokens to.
+    Address(String),
+    /// A contract address along with an optional message to execute on it
+    /// when delivering the tokens.
+    Contract {
+        /// The address of the recipient contract.
+        addr: String,
+        /// The JSON encoded ExecuteMsg to execute on the recipient contract.
+        msg: Option<Binary>,
+    },
+}
+
+impl JsonSchema for Recipient {
+    fn schema_name() -> String {
+        "Recipient".to_string()
+    }
+
+    fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+        RecipientSchema::json_schema(gen)
+    }
+}
+
+/// Callback message dispatched by the vault to the caller of `Deposit` or
+/// `Redeem` when the `callback` field is set. Allows contracts such as routers
+/// and credit managers to read the exact amounts minted or returned in the same
+/// transaction, without having to parse events.
+///
+/// The vault must dispatch this as the last message of the response, wrapped
+/// in [`VaultCallbackExecuteMsg`], so that the tokens have already been
+/// delivered when the callback is executed.
+#[cw_serde]
+pub enum VaultCallbackMsg {
+    /// Dispatched after a successful `Deposit`.
+    AfterDeposit {
+        /// The address that received the minted vault tokens.
+        recipient: String,
+        /// The amount of base tokens deposited.
+        assets_deposited: Uint128,
+        /// The amount of vault tokens minted.
+        shares_minted: Uint128,
+        /// The data passed in the `callback` field of `Deposit`.
+        data: Binary,
+    },
+    /// Dispatched after a successful `Redeem`.
+    AfterRedeem {
+        /// The address that received the returned base tokens.
+        recipient: String,
+        /// The amount of vault tokens burned.
+        shares_burned: Uint128,
+        /// The amount of base tokens returned.
+        assets_returned: Uint128,
+        /// The data passed in the `callback` field of `Redeem`.
+        data: Binary,
+    },
+}
+
+impl VaultCallbackMsg {
+    /// Convert a [`VaultCallbackMsg`] into a [`CosmosMsg`] executing it on the
+    /// caller contract, wrapped in [`VaultCallbackExecuteMsg`].
+    pub fn into_cosmos_msg(self, contract_addr: String) -> StdResult<CosmosMsg> {
+        Ok(WasmMsg::Execute {
+            contract_addr,
+            msg: to_json_binary(&VaultCallbackExecuteMsg::VaultCallback(self))?,
+            funds: vec![],
+        }
+        .into())
+    }
+}
+
+/// The ExecuteMsg variant that contracts wanting to receive vault callbacks
+/// must include in their own ExecuteMsg enum.
+#[cw_serde]
+pub enum VaultCallbackExecuteMsg {
+    /// A callback from a vault, see [`VaultCallbackMsg`].
+    VaultCallback(VaultCallbackMsg),
+}
+
 /// Contains ExecuteMsgs of all enabled extensions. To enable extensions defined
 /// outside of this crate, you can define your own `ExtensionExecuteMsg` type
 /// in your contract crate and pass it in as the generic parameter to ExecuteMsg
@@ -79,27 +472,88 @@ pub enum ExtensionExecuteMsg {
     Lockup(LockupExecuteMsg),
     #[cfg(feature = "force-unlock")]
     ForceUnlock(ForceUnlockExecuteMsg),
+    #[cfg(feature = "lockup-nft")]
+    LockupNft(LockupNftExecuteMsg),
+    #[cfg(feature = "instant-unlock")]
+    InstantUnlock(InstantUnlockExecuteMsg),
+    #[cfg(feature = "fee")]
+    Fee(FeeExecuteMsg),
+    #[cfg(feature = "admin")]
+    Admin(AdminExecuteMsg),
+    #[cfg(feature = "pause")]
+    Pause(PauseExecuteMsg),
+    #[cfg(feature = "whitelist")]
+    Whitelist(WhitelistExecuteMsg),
+    #[cfg(feature = "caps")]
+    Caps(CapsExecuteMsg),
+    #[cfg(feature = "autocompound")]
+    Autocompound(AutocompoundExecuteMsg),
+    #[cfg(feature = "rewards")]
+    Rewards(RewardsExecuteMsg),
+    #[cfg(feature = "zap")]
+    Zap(ZapExecuteMsg),
+    #[cfg(feature = "strategies")]
+    Strategies(StrategiesExecuteMsg),
+    #[cfg(feature = "async-redeem")]
+    AsyncRedeem(AsyncRedeemExecuteMsg),
+    #[cfg(feature = "rate-limit")]
+    RateLimit(RateLimitExecuteMsg),
+    #[cfg(feature = "referral")]
+    Referral(ReferralExecuteMsg),
+    #[cfg(feature = "vesting")]
+    Vesting(VestingExecuteMsg),
+    #[cfg(feature = "migration")]
+    Migration(MigrationExecuteMsg),
+    #[cfg(feature = "snapshot")]
+    Snapshot(SnapshotExecuteMsg),
+    #[cfg(feature = "insurance")]
+    Insurance(InsuranceExecuteMsg),
+    #[cfg(feature = "emergency-withdraw")]
+    EmergencyWithdraw(EmergencyWithdrawExecuteMsg),
+    #[cfg(feature = "performance-fee")]
+    PerformanceFee(PerformanceFeeExecuteMsg),
+    #[cfg(feature = "operator")]
+    Operator(OperatorExecuteMsg),
+    #[cfg(feature = "permit")]
+    Permit(PermitExecuteMsg),
+    #[cfg(feature = "leverage")]
+    Leverage(LeverageExecuteMsg),
+    #[cfg(feature = "compliance")]
+    Compliance(ComplianceExecuteMsg),
+    #[cfg(feature = "timelock")]
+    Timelock(TimelockExecuteMsg),
+    #[cfg(feature = "accounting")]
+    Accounting(AccountingExecuteMsg),
+    #[cfg(feature = "schedule")]
+    Schedule(ScheduleExecuteMsg),
+    #[cfg(feature = "ica")]
+    Ica(IcaExecuteMsg),
 }
 
 /// The default QueryMsg variants that all vaults must implement.
 /// This enum can be extended with additional variants by defining an extension
 /// enum and then passing it as the generic argument `T` to this enum.
 #[cw_serde]
-#[derive(QueryResponses)]
 pub enum VaultStandardQueryMsg<T = ExtensionQueryMsg>
 where
     T: JsonSchema,
 {
     /// Returns `VaultStandardInfoResponse` with information on the version of
     /// the vault standard used as well as any enabled extensions.
-    #[returns(VaultStandardInfoResponse)]
     VaultStandardInfo {},
 
     /// Returns `VaultInfoResponse` representing vault requirements, lockup, &
     /// vault token denom.
-    #[returns(VaultInfoResponse)]
     Info {},
 
+    /// Returns `VaultInfoExtendedResponse` with the information of `Info`, but
+    /// supporting multiple weighted base tokens. Implemented by vaults whose
+    /// underlying position consists of multiple tokens, such as basket and LP
+    /// vaults. Such vaults should still implement `Info` for backwards
+    /// compatibility, e.g. by returning the LP token as the base token.
+    #[cfg(feature = "multi-asset")]
+    InfoExtended {},
+
     /// Returns `Uint128` amount of vault tokens that will be returned for the
     /// passed in `amount` of base tokens.
     ///
@@ -114,7 +568,6 @@ where
         since = "0.4.1",
         note = "PreviewDeposit and PreviewRedeem turned out to be too difficult to implement in most cases. We recommend to use transaction simulation from non-contract clients such as frontends."
     )]
-    #[returns(Uint128)]
     PreviewDeposit {
         /// The amount of base tokens to preview depositing.
         amount: Uint128,
@@ -132,20 +585,57 @@ where
         since = "0.4.1",
         note = "PreviewDeposit and PreviewRedeem turned out to be too difficult to implement in most cases. We recommend to use transaction simulation from non-contract clients such as frontends."
     )]
-    #[returns(Uint128)]
     PreviewRedeem {
         /// The amount of vault tokens to preview redeeming.
         amount: Uint128,
     },
 
+    /// Returns `Uint128` amount of vault tokens that would be burned in
+    /// exchange for withdrawing exactly `amount` of base tokens.
+    ///
+    /// Allows an on-chain or off-chain user to simulate the effects of their
+    /// withdrawal at the current block, given current on-chain conditions.
+    ///
+    /// Must round up, returning as close to and no fewer than the exact amount
+    /// of vault tokens that would be burned in a withdraw call in the same
+    /// transaction.
+    PreviewWithdraw {
+        /// The amount of base tokens to preview withdrawing.
+        amount: Uint128,
+    },
+
+    /// Returns `Uint128` amount of base tokens that would be needed to mint
+    /// exactly `amount` of vault tokens.
+    ///
+    /// Allows an on-chain or off-chain user to simulate the effects of their
+    /// mint at the current block, given current on-chain conditions.
+    ///
+    /// Must round up, returning as close to and no fewer than the exact amount
+    /// of base tokens that would be deposited in a mint call in the same
+    /// transaction.
+    PreviewMint {
+        /// The amount of vault tokens to preview minting.
+        amount: Uint128,
+    },
+
+    /// Returns `Uint128` amount of vault tokens that will be returned for
+    /// depositing the passed in `assets` via `DepositMultiple`.
+    ///
+    /// Must return as close to and no more than the exact amount of vault
+    /// tokens that would be minted in a `DepositMultiple` call in the same
+    /// transaction.
+    #[cfg(feature = "multi-asset")]
+    PreviewDepositMultiple {
+        /// The tokens and amounts to preview depositing.
+        assets: Vec<(TokenUnchecked, Uint128)>,
+    },
+
     /// Returns the amount of assets managed by the vault denominated in base
     /// tokens. Useful for display purposes, and does not have to confer the
     /// exact amount of base tokens.
-    #[returns(Uint128)]
     TotalAssets {},
 
     /// Returns `Uint128` total amount of vault tokens in circulation.
-    #[returns(Uint128)]
     TotalVaultTokenSupply {},
 
     /// Returns the exchange rate of vault tokens quoted in terms of the
@@ -153,11 +643,14 @@ where
     /// `quote_denom` assets that can be exchanged for 1 unit of vault
     /// tokens.
     ///
+    /// This allows dashboards and oracles to read the share price directly,
+    /// instead of inferring it from `ConvertToAssets` with a synthetic amount.
+    ///
     /// May return an error if the quote denom is not supported by the vault.
-    #[returns(cosmwasm_std::Decimal)]
     VaultTokenExchangeRate {
-        /// The quote denom to quote the exchange rate in.
-        quote_denom: String,
+        /// The quote denom to quote the exchange rate in. If not set, the
+        /// exchange rate is quoted in the base token of the vault.
+        quote_denom: Option<String>,
     },
 
     /// The amount of vault tokens that the vault would exchange for the amount
@@ -170,10 +663,12 @@ where
     /// price-per-share, and instead should reflect the "average-user’s"
     /// price-per-share, meaning what the average user should expect to see
     /// when exchanging to and from.
-    #[returns(Uint128)]
     ConvertToShares {
         /// The amount of base tokens to convert to vault tokens.
         amount: Uint128,
+        /// The rounding direction to use for the conversion. If not set, the
+        /// vault may choose the rounding direction.
+        rounding: Option<Rounding>,
     },
 
     /// Returns the amount of base tokens that the Vault would exchange for
@@ -186,26 +681,308 @@ where
     /// price-per-share, and instead should reflect the "average-user’s"
     /// price-per-share, meaning what the average user should expect to see
     /// when exchanging to and from.
-    #[returns(Uint128)]
     ConvertToAssets {
         /// The amount of vault tokens to convert to base tokens.
         amount: Uint128,
+        /// The rounding direction to use for the conversion. If not set, the
+        /// vault may choose the rounding direction.
+        rounding: Option<Rounding>,
+    },
+
+    /// Returns `Uint128` result of converting `amount` between base tokens and
+    /// vault tokens using the exchange rate of the vault at a past block
+    /// height or time.
+    ///
+    /// Only supported by vaults that keep checkpoints of their exchange rate.
+    /// Should use the latest checkpoint at or before `at`, and must return an
+    /// error if the vault has no such checkpoint. Allows analytics tools and
+    /// dispute resolution contracts to reference past exchange rates.
+    HistoricalConvert {
+        /// The amount of tokens to convert.
+        amount: Uint128,
+        /// Whether to convert base tokens to vault tokens or vice versa.
+        direction: ConversionDirection,
+        /// The block height or time at which to use the exchange rate.
+        at: HistoricalPoint,
+    },
+
+    /// Returns `Uint128` maximum amount of base tokens that `owner` could
+    /// withdraw from the vault right now, by redeeming their vault tokens.
+    ///
+    /// Mirrors `maxWithdraw` from ERC-4626. Must take into account any limits
+    /// on withdrawals imposed by the vault, such as lockups or available
+    /// liquidity, and must not be higher than the actual amount of base tokens
+    /// that would be returned. May return 0 if withdrawals are disabled.
+    MaxWithdraw {
+        /// The address of the owner of the vault tokens.
+        owner: String,
+    },
+
+    /// Returns `MaxRedeemResponse` containing both the maximum amount of vault
+    /// tokens that `owner` could redeem right now, and the amount of base
+    /// tokens that redeeming them would return.
+    ///
+    /// Mirrors `maxRedeem` from ERC-4626, but returns both figures from the
+    /// same block so that integrators such as lending protocols don't need to
+    /// combine the results of two separate queries. `assets` must be equal to
+    /// what `MaxWithdraw` would return for the same owner.
+    MaxRedeem {
+        /// The address of the owner of the vault tokens.
+        owner: String,
+    },
+
+    /// Returns `DepositCapStatusResponse` describing the total deposit cap of
+    /// the vault, how much of it is currently used, and the remaining headroom,
+    /// all denominated in base tokens.
+    ///
+    /// Allows integrators routing deposits across multiple vaults to split
+    /// their orders without hitting the cap and reverting.
+    DepositCapStatus {},
+
+    /// Returns `MaxDepositResponse` describing how many base tokens could be
+    /// deposited into the vault for `recipient` right now.
+    ///
+    /// Mirrors `maxDeposit` from ERC-4626, but distinguishes the remaining
+    /// global capacity of the vault from the remaining capacity of the
+    /// recipient, and whether deposits are paused. This lets routers tell a
+    /// protocol wide limit apart from a recipient specific one.
+    MaxDeposit {
+        /// The address that would receive the vault tokens.
+        recipient: String,
+    },
+
+    /// Returns `OperationsStatusResponse` describing which operations of the
+    /// vault are currently enabled, along with an optional reason and resume
+    /// time for any disabled operations.
+    ///
+    /// Allows integrators to grey out actions in their UIs and skip paused
+    /// vaults when routing, without having to simulate transactions.
+    OperationsStatus {},
+
+    /// Returns `PositionValueResponse` containing the vault token balance of
+    /// `owner` along with its value denominated in base tokens, both read at
+    /// the same block.
+    ///
+    /// The base token value should be calculated the same way as
+    /// `ConvertToAssets` would for the owner's vault token balance.
+    PositionValue {
+        /// The address of the owner of the vault tokens.
+        owner: String,
+    },
+
+    /// Returns a `Vec<AssetAllocation>` describing where the assets managed by
+    /// the vault are held, e.g. idle balances held by the vault itself vs.
+    /// amounts deployed in each underlying strategy or position.
+    ///
+    /// The sum of the values of all entries should be consistent with
+    /// `TotalAssets`. Useful for risk dashboards and auditors.
+    TotalAssetsBreakdown {},
+
+    /// Returns `SupportsExtensionResponse` containing whether the vault
+    /// supports the extension with the given `name`, along with the version of
+    /// the extension used by the vault.
+    ///
+    /// Allows routers to feature detect extensions at runtime instead of
+    /// parsing the `extensions` list in `VaultStandardInfoResponse`. The
+    /// names used must match the names in that list, e.g. "lockup".
+    SupportsExtension {
+        /// The name of the extension.
+        name: String,
+    },
+
+    /// Returns `DetailedPreviewResponse` with the amount of vault tokens that
+    /// would be minted in exchange for depositing `amount` of base tokens,
+    /// along with a breakdown of all fees charged.
+    ///
+    /// `gross_amount` is the amount of vault tokens before fees and
+    /// `net_amount` the amount actually minted, which follows the same rules
+    /// as `PreviewDeposit`.
+    PreviewDepositDetailed {
+        /// The amount of base tokens to preview depositing.
+        amount: Uint128,
     },
 
-    /// Handle queries of any enabled extensions.
-    #[returns(Empty)]
+    /// Returns `DetailedPreviewResponse` with the amount of base tokens that
+    /// would be returned in exchange for redeeming `amount` of vault tokens,
+    /// along with a breakdown of all fees charged.
+    ///
+    /// `gross_amount` is the amount of base tokens before fees and
+    /// `net_amount` the amount actually returned, which follows the same
+    /// rules as `PreviewRedeem`.
+    PreviewRedeemDetailed {
+        /// The amount of vault tokens to preview redeeming.
+        amount: Uint128,
+    },
+
+    /// Returns a `Vec<Binary>` containing the JSON encoded responses of each of
+    /// the passed in queries, in the same order as the queries.
+    ///
+    /// Allows indexers and frontends to fetch e.g. `Info`, `TotalAssets` and
+    /// `TotalVaultTokenSupply` in a single round trip, with all responses
+    /// reflecting the same block. The entire query must fail if any of the
+    /// batched queries fail. Vaults may reject nested `Batch` queries.
+    ///
+    /// See [`crate::decode_batch_response`] for decoding the responses into
+    /// their typed values.
+    Batch {
+        /// The queries to execute.
+        queries: Vec<VaultStandardQueryMsg<T>>,
+    },
+
+    /// Handle queries of any enabled extensions. The responses of the extension
+    /// queries are defined by the `QueryResponses` implementation of `T`, see
+    /// e.g. [`ExtensionQueryMsg`].
     VaultExtension(T),
 }
 
+/// Implemented manually instead of derived, so that the response schemas of the
+/// extension queries of `T` are included in place of the `VaultExtension`
+/// variant. This gives generated clients the correct response types for
+/// extension queries.
+impl<T> QueryResponses for VaultStandardQueryMsg<T>
+where
+    T: QueryResponses,
+{
+    fn response_schemas_impl() -> BTreeMap<String, RootSchema> {
+        let mut responses = BTreeMap::from([
+            (
+                "vault_standard_info".to_string(),
+                schema_for!(VaultStandardInfoResponse),
+            ),
+            ("info".to_string(), schema_for!(VaultInfoResponse)),
+            ("preview_deposit".to_string(), schema_for!(Uint128)),
+            ("preview_redeem".to_string(), schema_for!(Uint128)),
+            ("preview_withdraw".to_string(), schema_for!(Uint128)),
+            ("preview_mint".to_string(), schema_for!(Uint128)),
+            ("total_assets".to_string(), schema_for!(Uint128)),
+            ("total_vault_token_supply".to_string(), schema_for!(Uint128)),
+            (
+                "vault_token_exchange_rate".to_string(),
+                schema_for!(cosmwasm_std::Decimal),
+            ),
+            ("convert_to_shares".to_string(), schema_for!(Uint128)),
+            ("convert_to_assets".to_string(), schema_for!(Uint128)),
+            ("historical_convert".to_string(), schema_for!(Uint128)),
+            ("max_withdraw".to_string(), schema_for!(Uint128)),
+            ("max_redeem".to_string(), schema_for!(MaxRedeemResponse)),
+            (
+                "deposit_cap_status".to_string(),
+                schema_for!(DepositCapStatusResponse),
+            ),
+            ("max_deposit".to_string(), schema_for!(MaxDepositResponse)),
+            (
+                "operations_status".to_string(),
+                schema_for!(OperationsStatusResponse),
+            ),
+            (
+                "position_value".to_string(),
+                schema_for!(PositionValueResponse),
+            ),
+            (
+                "total_assets_breakdown".to_string(),
+                schema_for!(Vec<AssetAllocation>),
+            ),
+            (
+                "supports_extension".to_string(),
+                schema_for!(SupportsExtensionResponse),
+            ),
+            (
+                "preview_deposit_detailed".to_string(),
+                schema_for!(DetailedPreviewResponse),
+            ),
+            (
+                "preview_redeem_detailed".to_string(),
+                schema_for!(DetailedPreviewResponse),
+            ),
+            ("batch".to_string(), schema_for!(Vec<Binary>)),
+        ]);
+        #[cfg(feature = "multi-asset")]
+        responses.insert(
+            "info_extended".to_string(),
+            schema_for!(VaultInfoExtendedResponse),
+        );
+        #[cfg(feature = "multi-asset")]
+        responses.insert("preview_deposit_multiple".to_string(), schema_for!(Uint128));
+        responses.extend(T::response_schemas_impl());
+        responses
+    }
+}
+
 /// Contains QueryMsgs of all enabled extensions. To enable extensions defined
 /// outside of this crate, you can define your own `ExtensionQueryMsg` type
 /// in your contract crate and pass it in as the generic parameter to QueryMsg
 #[cw_serde]
+#[derive(QueryResponses)]
+#[query_responses(nested)]
 pub enum ExtensionQueryMsg {
     #[cfg(feature = "keeper")]
     Keeper(KeeperQueryMsg),
     #[cfg(feature = "lockup")]
     Lockup(LockupQueryMsg),
+    #[cfg(feature = "force-unlock")]
+    ForceUnlock(ForceUnlockQueryMsg),
+    #[cfg(feature = "lockup-nft")]
+    LockupNft(LockupNftQueryMsg),
+    #[cfg(feature = "instant-unlock")]
+    InstantUnlock(InstantUnlockQueryMsg),
+    #[cfg(feature = "fee")]
+    Fee(FeeQueryMsg),
+    #[cfg(feature = "admin")]
+    Admin(AdminQueryMsg),
+    #[cfg(feature = "pause")]
+    Pause(PauseQueryMsg),
+    #[cfg(feature = "whitelist")]
+    Whitelist(WhitelistQueryMsg),
+    #[cfg(feature = "caps")]
+    Caps(CapsQueryMsg),
+    #[cfg(feature = "autocompound")]
+    Autocompound(AutocompoundQueryMsg),
+    #[cfg(feature = "rewards")]
+    Rewards(RewardsQueryMsg),
+    #[cfg(feature = "zap")]
+    Zap(ZapQueryMsg),
+    #[cfg(feature = "oracle")]
+    Oracle(OracleQueryMsg),
+    #[cfg(feature = "strategies")]
+    Strategies(StrategiesQueryMsg),
+    #[cfg(feature = "async-redeem")]
+    AsyncRedeem(AsyncRedeemQueryMsg),
+    #[cfg(feature = "rate-limit")]
+    RateLimit(RateLimitQueryMsg),
+    #[cfg(feature = "referral")]
+    Referral(ReferralQueryMsg),
+    #[cfg(feature = "vesting")]
+    Vesting(VestingQueryMsg),
+    #[cfg(feature = "migration")]
+    Migration(MigrationQueryMsg),
+    #[cfg(feature = "snapshot")]
+    Snapshot(SnapshotQueryMsg),
+    #[cfg(feature = "voting-power")]
+    VotingPower(VotingPowerQueryMsg),
+    #[cfg(feature = "insurance")]
+    Insurance(InsuranceQueryMsg),
+    #[cfg(feature = "emergency-withdraw")]
+    EmergencyWithdraw(EmergencyWithdrawQueryMsg),
+    #[cfg(feature = "performance-fee")]
+    PerformanceFee(PerformanceFeeQueryMsg),
+    #[cfg(feature = "operator")]
+    Operator(OperatorQueryMsg),
+    #[cfg(feature = "permit")]
+    Permit(PermitQueryMsg),
+    #[cfg(feature = "leverage")]
+    Leverage(LeverageQueryMsg),
+    #[cfg(feature = "compliance")]
+    Compliance(ComplianceQueryMsg),
+    #[cfg(feature = "timelock")]
+    Timelock(TimelockQueryMsg),
+    #[cfg(feature = "metrics")]
+    Metrics(MetricsQueryMsg),
+    #[cfg(feature = "accounting")]
+    Accounting(AccountingQueryMsg),
+    #[cfg(feature = "schedule")]
+    Schedule(ScheduleQueryMsg),
+    #[cfg(feature = "ica")]
+    Ica(IcaQueryMsg),
 }
 
 /// Struct returned from QueryMsg::VaultStandardInfo with information about the
@@ -234,4 +1011,376 @@ pub struct VaultInfoResponse {
     /// Vault token. The denom if it is a native token and the contract address
     /// if it is a cw20 token.
     pub vault_token: String,
+    /// The minimum amount of base tokens that can be deposited at once.
+    /// Deposits of fewer base tokens must fail with
+    /// [`crate::VaultStandardError::BelowMinimum`]. `None` if there is no
+    /// minimum.
+    pub min_deposit: Option<Uint128>,
+    /// The minimum amount of vault tokens that can be redeemed at once.
+    /// Redemptions of fewer vault tokens must fail with
+    /// [`crate::VaultStandardError::BelowMinimum`]. `None` if there is no
+    /// minimum.
+    pub min_redeem: Option<Uint128>,
+    /// The number of decimals of the base token. If not set, it can be
+    /// resolved with [`VaultInfoResponse::resolve_base_token_decimals`].
+    pub base_token_decimals: Option<u8>,
+    /// The number of decimals of the vault token. If not set, it can be
+    /// resolved with [`VaultInfoResponse::resolve_vault_token_decimals`].
+    pub vault_token_decimals: Option<u8>,
+}
+
+impl VaultInfoResponse {
+    /// Returns `base_token_decimals` if set, and otherwise queries the decimals
+    /// of `base_token`, which must be the token identified by the `base_token`
+    /// field. See [`Token::query_decimals`].
+    pub fn resolve_base_token_decimals(
+        &self,
+        querier: &QuerierWrapper,
+        base_token: &Token,
+    ) -> StdResult<u8> {
+        match self.base_token_decimals {
+            Some(decimals) => Ok(decimals),
+            None => base_token.query_decimals(querier),
+        }
+    }
+
+    /// Returns `vault_token_decimals` if set, and otherwise queries the
+    /// decimals of `vault_token`, which must be the token identified by the
+    /// `vault_token` field. See [`Token::query_decimals`].
+    pub fn resolve_vault_token_decimals(
+        &self,
+        querier: &QuerierWrapper,
+        vault_token: &Token,
+    ) -> StdResult<u8> {
+        match self.vault_token_decimals {
+            Some(decimals) => Ok(decimals),
+            None => vault_token.query_decimals(querier),
+        }
+    }
+}
+
+/// A base token of a multi-asset vault along with its weight.
+#[cfg(feature = "multi-asset")]
+#[cw_serde]
+pub struct WeightedToken {
+    /// The token.
+    pub token: Token,
+    /// The weight of the token in the underlying position of the vault. The
+    /// weights of all base tokens of a vault should sum to one.
+    pub weight: Decimal,
+}
+
+/// Returned by QueryMsg::InfoExtended and contains information about a vault
+/// with possibly multiple base tokens.
+#[cfg(feature = "multi-asset")]
+#[cw_serde]
+pub struct VaultInfoExtendedResponse {
+    /// The tokens that are accepted for deposits and withdrawals, along with
+    /// their weights.
+    pub base_tokens: Vec<WeightedToken>,
+    /// Vault token. The denom if it is a native token and the contract address
+    /// if it is a cw20 token.
+    pub vault_token: String,
+    /// The minimum amount of vault tokens that can be redeemed at once. `None`
+    /// if there is no minimum.
+    pub min_redeem: Option<Uint128>,
+}
+
+#[cfg(feature = "multi-asset")]
+impl VaultInfoExtendedResponse {
+    /// Creates a VaultInfoExtendedResponse for a vault with a single base
+    /// token, from its legacy [`VaultInfoResponse`]. `base_token` must be the
+    /// token identified by the `base_token` field of `info`.
+    pub fn from_single(info: VaultInfoResponse, base_token: Token) -> Self {
+        Self {
+            base_tokens: vec![WeightedToken {
+                token: base_token,
+                weight: Decimal::one(),
+            }],
+            vault_token: info.vault_token,
+            min_redeem: info.min_redeem,
+        }
+    }
+}
+
+#[cfg(feature = "multi-asset")]
+impl TryFrom<VaultInfoExtendedResponse> for VaultInfoResponse {
+    type Error = StdError;
+
+    /// Converts into the legacy [`VaultInfoResponse`]. Fails if the vault has
+    /// more than one base token.
+    fn try_from(info: VaultInfoExtendedResponse) -> StdResult<Self> {
+        let base_token = match info.base_tokens.as_slice() {
+            [WeightedToken { token, .. }] => match token {
+                Token::Native(denom) => denom.clone(),
+                Token::Cw20(addr) => addr.to_string(),
+            },
+            _ => {
+                return Err(StdError::generic_err(format!(
+                    "Expected a single base token, got {}",
+                    info.base_tokens.len()
+                )))
+            }
+        };
+        Ok(Self {
+            base_token,
+            vault_token: info.vault_token,
+            min_deposit: None,
+            min_redeem: info.min_redeem,
+            base_token_decimals: None,
+            vault_token_decimals: None,
+        })
+    }
+}
+
+/// The rounding direction to use when converting between base tokens and
+/// vault tokens.
+///
+/// Lending protocols need to round down when valuing collateral, and may want
+/// to round up when quoting amounts to users.
+#[cw_serde]
+pub enum Rounding {
+    /// Round down towards zero.
+    Floor,
+    /// Round up away from zero.
+    Ceil,
+}
+
+/// The direction of a conversion between base tokens and vault tokens.
+#[cw_serde]
+pub enum ConversionDirection {
+    /// Convert an amount of base tokens to vault tokens.
+    ToShares,
+    /// Convert an amount of vault tokens to base tokens.
+    ToAssets,
+}
+
+/// A point in the history of the chain, identified either by block height or
+/// by block time.
+#[cw_serde]
+pub enum HistoricalPoint {
+    /// A block height.
+    Height(u64),
+    /// A block time.
+    Time(Timestamp),
+}
+
+/// Returned by QueryMsg::MaxRedeem and contains the maximum amount of vault
+/// tokens an owner can redeem, along with the equivalent amount of base tokens.
+#[cw_serde]
+pub struct MaxRedeemResponse {
+    /// The maximum amount of vault tokens that can be redeemed.
+    pub shares: Uint128,
+    /// The amount of base tokens that would be returned by redeeming `shares`
+    /// vault tokens.
+    pub assets: Uint128,
+}
+
+/// Returned by QueryMsg::MaxDeposit and contains the limits on how many base
+/// tokens can currently be deposited into the vault for a recipient.
+#[cw_serde]
+pub struct MaxDepositResponse {
+    /// The amount of base tokens that can still be deposited into the vault
+    /// by anyone. `None` if the vault has no global limit.
+    pub global_remaining: Option<Uint128>,
+    /// The amount of base tokens that can still be deposited for the
+    /// recipient. `None` if the vault has no per recipient limit.
+    pub recipient_remaining: Option<Uint128>,
+    /// Whether deposits are currently paused.
+    pub paused: bool,
+}
+
+impl MaxDepositResponse {
+    /// Returns the maximum amount of base tokens that can be deposited for the
+    /// recipient, taking all limits into account. `None` if deposits are
+    /// unlimited.
+    pub fn max_deposit(&self) -> Option<Uint128> {
+        if self.paused {
+            return Some(Uint128::zero());
+        }
+        match (self.global_remaining, self.recipient_remaining) {
+            (Some(global), Some(recipient)) => Some(global.min(recipient)),
+            (global, recipient) => global.or(recipient),
+        }
+    }
+}
+
+/// The status of a single operation of the vault.
+#[cw_serde]
+pub struct OperationStatus {
+    /// Whether the operation is currently enabled.
+    pub enabled: bool,
+    /// A human readable reason for why the operation is disabled, if any.
+    pub reason: Option<String>,
+    /// The time at which the operation is expected to be enabled again, if
+    /// known.
+    pub resume_at: Option<Timestamp>,
+}
+
+/// Returned by QueryMsg::OperationsStatus and contains the status of each of
+/// the operations of the vault.
+#[cw_serde]
+pub struct OperationsStatusResponse {
+    /// The status of deposits, including `Deposit`, `DepositAll` and `Mint`.
+    pub deposit: OperationStatus,
+    /// The status of redemptions, including `Redeem`, `RedeemAll` and
+    /// `Withdraw`.
+    pub redeem: OperationStatus,
+    /// The status of unlocking through the lockup extension. `None` if the
+    /// vault does not implement the lockup extension.
+    pub unlock: Option<OperationStatus>,
+}
+
+/// Returned by QueryMsg::PositionValue and contains the vault token balance of
+/// an owner and its value in base tokens.
+#[cw_serde]
+pub struct PositionValueResponse {
+    /// The amount of vault tokens held by the owner.
+    pub vault_token_amount: Uint128,
+    /// The value of `vault_token_amount` denominated in base tokens.
+    pub base_token_amount: Uint128,
+}
+
+/// Returned by QueryMsg::DepositCapStatus and contains information about the
+/// deposit cap of the vault, denominated in base tokens.
+#[cw_serde]
+pub struct DepositCapStatusResponse {
+    /// The total amount of base tokens that can be deposited into the vault.
+    /// `None` if the vault has no deposit cap.
+    pub total_cap: Option<Uint128>,
+    /// The amount of the deposit cap that is currently used.
+    pub used: Uint128,
+    /// The amount of base tokens that can still be deposited before the cap is
+    /// reached. `None` if the vault has no deposit cap.
+    pub remaining: Option<Uint128>,
+}
+
+/// An entry in the response of QueryMsg::TotalAssetsBreakdown, describing an
+/// amount of tokens held in a specific place.
+#[cw_serde]
+pub struct AssetAllocation {
+    /// A human readable label describing where the tokens are held. E.g.
+    /// "idle" or the name of a strategy.
+    pub label: String,
+    /// The token and amount held.
+    pub amount: TokenAmount,
+}
+
+/// Returned by QueryMsg::SupportsExtension and contains information about
+/// whether an extension is supported by the vault.
+#[cw_serde]
+pub struct SupportsExtensionResponse {
+    /// Whether the extension is supported by the vault.
+    pub supported: bool,
+    /// The version of the extension used by the vault as a semver compliant
+    /// string, if the extension is supported.
+    pub version: Option<String>,
+}
+
+/// The type of a fee charged by the vault.
+#[cw_serde]
+pub enum FeeType {
+    /// A fee charged on deposits.
+    Deposit,
+    /// A fee charged on redemptions and withdrawals.
+    Exit,
+    /// A penalty charged for exiting a lockup early.
+    LockupPenalty,
+    /// Any other type of fee, described by the contained string.
+    Other(String),
+}
+
+/// A fee charged by the vault.
+#[cw_serde]
+pub struct Fee {
+    /// The type of the fee.
+    pub fee_type: FeeType,
+    /// The token and amount charged.
+    pub amount: TokenAmount,
+}
+
+/// Returned by QueryMsg::PreviewDepositDetailed and
+/// QueryMsg::PreviewRedeemDetailed and contains the result of the previewed
+/// action before and after fees.
+#[cw_serde]
+pub struct DetailedPreviewResponse {
+    /// The amount of tokens that would be returned before any fees are
+    /// charged.
+    pub gross_amount: Uint128,
+    /// The fees that would be charged.
+    pub fees: Vec<Fee>,
+    /// The amount of tokens that would be returned after all fees are
+    /// charged.
+    pub net_amount: Uint128,
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{from_json, to_json_string};
+
+    use super::*;
+
+    #[test]
+    fn recipient_deserializes_from_plain_address() {
+        let recipient: Recipient = from_json(br#""addr""#).unwrap();
+        assert_eq!(recipient, Recipient::from("addr"));
+
+        let msg: VaultStandardExecuteMsg =
+            from_json(br#"{"deposit":{"amount":"100","recipient":"addr"}}"#).unwrap();
+        match msg {
+            VaultStandardExecuteMsg::Deposit { recipient, .. } => {
+                assert_eq!(recipient, Some(Recipient::from("addr")))
+            }
+            _ => panic!("expected Deposit"),
+        }
+    }
+
+    #[test]
+    fn recipient_deserializes_from_object() {
+        let recipient: Recipient = from_json(br#"{"addr":"addr","msg":"e30="}"#).unwrap();
+        assert_eq!(recipient, Recipient::with_msg("addr", Binary::from(b"{}")));
+
+        let recipient: Recipient = from_json(br#"{"addr":"addr"}"#).unwrap();
+        assert_eq!(recipient, Recipient::from("addr"));
+    }
+
+    #[test]
+    fn recipient_serde_round_trip() {
+        let recipient = Recipient::from("addr");
+        let json = to_json_string(&recipient).unwrap();
+        assert_eq!(json, r#""addr""#);
+        assert_eq!(from_json::<Recipient>(&json).unwrap(), recipient);
+
+        let recipient = Recipient::with_msg("addr", Binary::from(b"{}"));
+        let json = to_json_string(&recipient).unwrap();
+        assert_eq!(json, r#"{"addr":"addr","msg":"e30="}"#);
+        assert_eq!(from_json::<Recipient>(&json).unwrap(), recipient);
+    }
+
+    #[test]
+    fn recipient_rejects_unknown_shapes() {
+        for json in [
+            r#"{"address":{"addr":"addr"}}"#,
+            r#"{"addr":"addr","funds":[]}"#,
+            r#"{"msg":"e30="}"#,
+            r#"1"#,
+            r#"null"#,
+        ] {
+            assert!(from_json::<Recipient>(json).is_err(), "{}", json);
+        }
+    }
+
+    #[cfg(feature = "lockup")]
+    #[test]
+    fn query_responses_include_extension_queries() {
+        use crate::extensions::lockup::UnlockingPosition;
+
+        let responses = VaultStandardQueryMsg::<ExtensionQueryMsg>::response_schemas_impl();
+        assert_eq!(responses["info"], schema_for!(VaultInfoResponse));
+        assert_eq!(
+            responses["unlocking_positions"],
+            schema_for!(Vec<UnlockingPosition>)
+        );
+        assert!(!responses.contains_key("vault_extension"));
+    }
 }
@@ -0,0 +1,694 @@
+use std::fmt;
+use std::str::FromStr;
+
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    coin, to_json_binary, Addr, Api, BankMsg, Coin, CosmosMsg, QuerierWrapper, StdError, StdResult,
+    Uint128, Uint256, WasmMsg,
+};
+use cw20::{BalanceResponse, Cw20ExecuteMsg, Cw20QueryMsg, TokenInfoResponse};
+#[cfg(feature = "storage")]
+use cw_storage_plus::{Key, KeyDeserialize, Prefixer, PrimaryKey};
+use schemars::JsonSchema;
+use serde::de::{self, IgnoredAny, MapAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize};
+
+/// A token that can be held by a vault, either a Cosmos native token or a cw20
+/// token.
+///
+/// Serializes as `{"native": "<denom>"}` or `{"cw20": "<address>"}`. For
+/// backwards compatibility with vaults that stored plain denoms, a plain string
+/// also deserializes into [`Token::Native`].
+#[derive(Serialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Token {
+    /// A Cosmos native token, identified by its denom.
+    Native(String),
+    /// A cw20 token, identified by its contract address.
+    Cw20(Addr),
+}
+
+/// Implemented with a visitor instead of an untagged enum, since untagged
+/// enums buffer their input and can pull float operations into the wasm.
+impl<'de> Deserialize<'de> for Token {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(TokenVisitor)
+    }
+}
+
+struct TokenVisitor;
+
+impl<'de> Visitor<'de> for TokenVisitor {
+    type Value = Token;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a denom or an object with a single `native` or `cw20` key")
+    }
+
+    fn visit_str<E: de::Error>(self, denom: &str) -> Result<Token, E> {
+        Ok(Token::Native(denom.to_string()))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Token, A::Error> {
+        #[derive(Deserialize)]
+        #[serde(field_identifier, rename_all = "snake_case")]
+        enum Kind {
+            Native,
+            Cw20,
+        }
+
+        let token = match map.next_key()? {
+            Some(Kind::Native) => Token::Native(map.next_value()?),
+            Some(Kind::Cw20) => Token::Cw20(map.next_value()?),
+            None => return Err(de::Error::invalid_length(0, &self)),
+        };
+        if map.next_key::<IgnoredAny>()?.is_some() {
+            return Err(de::Error::invalid_length(2, &self));
+        }
+        Ok(token)
+    }
+}
+
+/// Formats the token in its canonical string format, `native:<denom>` or
+/// `cw20:<address>`.
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Token::Native(denom) => write!(f, "native:{}", denom),
+            Token::Cw20(addr) => write!(f, "cw20:{}", addr),
+        }
+    }
+}
+
+/// Parses a token from its canonical string format, `native:<denom>` or
+/// `cw20:<address>`. The address of a cw20 token is not validated, call
+/// [`Token::validate`] to do so.
+impl FromStr for Token {
+    type Err = StdError;
+
+    fn from_str(s: &str) -> StdResult<Self> {
+        match s.split_once(':') {
+            Some(("native", denom)) => Ok(Token::Native(denom.to_string())),
+            Some(("cw20", addr)) => Ok(Token::Cw20(Addr::unchecked(addr))),
+            _ => Err(StdError::parse_err(
+                "Token",
+                format!("Invalid token format: {}", s),
+            )),
+        }
+    }
+}
+
+/// Storage key of a [`Token`], usable on its own or as an element of a
+/// composite key.
+///
+/// The key is stored as a single key part containing the canonical string
+/// format of the token (see [`Token`]'s `Display` implementation) and
+/// deserializes back into a [`Token`].
+#[cfg(feature = "storage")]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct TokenKey(String);
+
+#[cfg(feature = "storage")]
+impl From<&Token> for TokenKey {
+    fn from(token: &Token) -> Self {
+        Self(token.to_string())
+    }
+}
+
+#[cfg(feature = "storage")]
+impl From<Token> for TokenKey {
+    fn from(token: Token) -> Self {
+        Self::from(&token)
+    }
+}
+
+#[cfg(feature = "storage")]
+impl<'a> PrimaryKey<'a> for TokenKey {
+    type Prefix = ();
+    type SubPrefix = ();
+    type Suffix = Self;
+    type SuperSuffix = Self;
+
+    fn key(&self) -> Vec<Key<'_>> {
+        vec![Key::Ref(self.0.as_bytes())]
+    }
+}
+
+#[cfg(feature = "storage")]
+impl<'a> Prefixer<'a> for TokenKey {
+    fn prefix(&self) -> Vec<Key<'_>> {
+        vec![Key::Ref(self.0.as_bytes())]
+    }
+}
+
+#[cfg(feature = "storage")]
+impl KeyDeserialize for TokenKey {
+    type Output = Token;
+
+    fn from_vec(value: Vec<u8>) -> StdResult<Self::Output> {
+        String::from_utf8(value)
+            .map_err(StdError::invalid_utf8)?
+            .parse()
+    }
+}
+
+impl PartialEq<Coin> for Token {
+    fn eq(&self, coin: &Coin) -> bool {
+        self.matches(coin)
+    }
+}
+
+/// An unvalidated version of [`Token`], to be used in messages. Call
+/// [`TokenUnchecked::check`] to validate it and get a [`Token`].
+#[cw_serde]
+pub enum TokenUnchecked {
+    /// A Cosmos native token, identified by its denom.
+    Native(String),
+    /// A cw20 token, identified by its unvalidated contract address.
+    Cw20(String),
+}
+
+impl TokenUnchecked {
+    /// Validates the denom of a native token or the address of a cw20 token and
+    /// returns the checked [`Token`].
+    pub fn check(&self, api: &dyn Api) -> StdResult<Token> {
+        match self {
+            TokenUnchecked::Native(denom) => {
+                validate_native_denom(denom)?;
+                Ok(Token::Native(denom.clone()))
+            }
+            TokenUnchecked::Cw20(addr) => Ok(Token::Cw20(api.addr_validate(addr)?)),
+        }
+    }
+}
+
+impl From<Token> for TokenUnchecked {
+    fn from(token: Token) -> Self {
+        match token {
+            Token::Native(denom) => TokenUnchecked::Native(denom),
+            Token::Cw20(addr) => TokenUnchecked::Cw20(addr.to_string()),
+        }
+    }
+}
+
+/// Validates that `denom` follows the format of Cosmos SDK denoms: 3 to 128
+/// characters, starting with a letter and followed by letters, digits or any
+/// of `/:._-`.
+fn validate_native_denom(denom: &str) -> StdResult<()> {
+    let mut chars = denom.chars();
+    let valid = (3..=128).contains(&denom.len())
+        && chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+        && chars.all(|c| c.is_ascii_alphanumeric() || "/:._-".contains(c));
+    if !valid {
+        return Err(StdError::generic_err(format!("Invalid denom: {}", denom)));
+    }
+    Ok(())
+}
+
+impl Token {
+    /// Validates the denom of a native token or the address of a cw20 token.
+    pub fn validate(&self, api: &dyn Api) -> StdResult<()> {
+        TokenUnchecked::from(self.clone()).check(api).map(|_| ())
+    }
+
+    /// Returns whether `coin` is of this token, i.e. whether the token is a
+    /// native token with the same denom as `coin`.
+    pub fn matches(&self, coin: &Coin) -> bool {
+        match self {
+            Token::Native(denom) => *denom == coin.denom,
+            Token::Cw20(_) => false,
+        }
+    }
+
+    /// Returns the amount of this token in `funds`, or `None` if `funds` does
+    /// not contain this token. Always `None` for cw20 tokens, which must be
+    /// sent via `Receive` instead.
+    pub fn find_in_funds(&self, funds: &[Coin]) -> Option<Uint128> {
+        funds
+            .iter()
+            .find(|coin| self.matches(coin))
+            .map(|coin| coin.amount)
+    }
+
+    /// Returns whether the token is a native token transferred over IBC, i.e.
+    /// its denom starts with `ibc/`.
+    pub fn is_ibc(&self) -> bool {
+        self.ibc_hash().is_some()
+    }
+
+    /// Returns the hash of the denom trace of the token, if it is a native
+    /// token transferred over IBC.
+    pub fn ibc_hash(&self) -> Option<&str> {
+        match self {
+            Token::Native(denom) => denom.strip_prefix("ibc/"),
+            Token::Cw20(_) => None,
+        }
+    }
+
+    /// Queries the denom trace of an IBC token, containing the path over which
+    /// the token was transferred and its denom on the origin chain. Fails if
+    /// the token is not an IBC token.
+    #[cfg(feature = "ibc-denom-trace")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ibc-denom-trace")))]
+    pub fn query_denom_trace(&self, querier: &QuerierWrapper) -> StdResult<DenomTrace> {
+        use osmosis_std::types::ibc::applications::transfer::v1::TransferQuerier;
+
+        let hash = self
+            .ibc_hash()
+            .ok_or_else(|| StdError::generic_err(format!("Not an IBC token: {:?}", self)))?;
+        let trace = TransferQuerier::new(querier)
+            .denom_trace(hash.to_string())?
+            .denom_trace
+            .ok_or_else(|| StdError::not_found(format!("Denom trace of {}", hash)))?;
+        Ok(DenomTrace {
+            path: trace.path,
+            base_denom: trace.base_denom,
+        })
+    }
+
+    /// Returns a [`CosmosMsg`] transferring `amount` of the token to `to`. This
+    /// is a `BankMsg::Send` for native tokens and a `Cw20ExecuteMsg::Transfer`
+    /// for cw20 tokens.
+    pub fn transfer_msg(&self, to: &Addr, amount: Uint128) -> StdResult<CosmosMsg> {
+        Ok(match self {
+            Token::Native(denom) => BankMsg::Send {
+                to_address: to.to_string(),
+                amount: vec![coin(amount.u128(), denom)],
+            }
+            .into(),
+            Token::Cw20(contract_addr) => WasmMsg::Execute {
+                contract_addr: contract_addr.to_string(),
+                msg: to_json_binary(&Cw20ExecuteMsg::Transfer {
+                    recipient: to.to_string(),
+                    amount,
+                })?,
+                funds: vec![],
+            }
+            .into(),
+        })
+    }
+
+    /// Queries the balance of the token held by `address`. This is a bank
+    /// balance query for native tokens and a `Cw20QueryMsg::Balance` query for
+    /// cw20 tokens.
+    pub fn query_balance(
+        &self,
+        querier: &QuerierWrapper,
+        address: impl Into<String>,
+    ) -> StdResult<Uint128> {
+        match self {
+            Token::Native(denom) => Ok(querier.query_balance(address, denom)?.amount),
+            Token::Cw20(contract_addr) => {
+                let res: BalanceResponse = querier.query_wasm_smart(
+                    contract_addr,
+                    &Cw20QueryMsg::Balance {
+                        address: address.into(),
+                    },
+                )?;
+                Ok(res.balance)
+            }
+        }
+    }
+
+    /// Queries the number of decimals of the token. For cw20 tokens this is
+    /// read from the `TokenInfo` query. For native tokens it is the exponent
+    /// of the display denom in the bank denom metadata, which requires the
+    /// `denom-metadata` feature.
+    pub fn query_decimals(&self, querier: &QuerierWrapper) -> StdResult<u8> {
+        match self {
+            Token::Native(denom) => query_native_decimals(querier, denom),
+            Token::Cw20(contract_addr) => {
+                let res: TokenInfoResponse =
+                    querier.query_wasm_smart(contract_addr, &Cw20QueryMsg::TokenInfo {})?;
+                Ok(res.decimals)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "denom-metadata")]
+fn query_native_decimals(querier: &QuerierWrapper, denom: &str) -> StdResult<u8> {
+    let metadata = querier.query_denom_metadata(denom)?;
+    let exponent = metadata
+        .denom_units
+        .iter()
+        .find(|unit| unit.denom == metadata.display)
+        .map(|unit| unit.exponent)
+        .ok_or_else(|| {
+            StdError::generic_err(format!("No display denom unit in metadata of {}", denom))
+        })?;
+    u8::try_from(exponent).map_err(|_| {
+        StdError::generic_err(format!("Exponent {} of {} is too large", exponent, denom))
+    })
+}
+
+#[cfg(not(feature = "denom-metadata"))]
+fn query_native_decimals(_querier: &QuerierWrapper, denom: &str) -> StdResult<u8> {
+    Err(StdError::generic_err(format!(
+        "Cannot query decimals of native token {} without the denom-metadata feature",
+        denom
+    )))
+}
+
+/// The origin of a native token transferred over IBC.
+#[cw_serde]
+pub struct DenomTrace {
+    /// The chain of port and channel identifiers over which the token was
+    /// transferred, e.g. "transfer/channel-0".
+    pub path: String,
+    /// The denom of the token on its origin chain.
+    pub base_denom: String,
+}
+
+/// An amount of a [`Token`].
+#[cw_serde]
+pub struct TokenAmount {
+    /// The token.
+    pub token: Token,
+    /// The amount of `token`.
+    pub amount: Uint128,
+}
+
+impl TokenAmount {
+    /// Create a new TokenAmount instance.
+    pub fn new(token: Token, amount: impl Into<Uint128>) -> Self {
+        Self {
+            token,
+            amount: amount.into(),
+        }
+    }
+
+    /// Create a TokenAmount of a native token from a [`Coin`].
+    pub fn from_coin(coin: Coin) -> Self {
+        Self {
+            token: Token::Native(coin.denom),
+            amount: coin.amount,
+        }
+    }
+
+    /// Returns the amount as a [`Coin`]. Fails if the token is a cw20 token.
+    pub fn to_coin(&self) -> StdResult<Coin> {
+        match &self.token {
+            Token::Native(denom) => Ok(coin(self.amount.u128(), denom)),
+            Token::Cw20(addr) => Err(StdError::generic_err(format!(
+                "Cannot convert cw20 token {} to a coin",
+                addr
+            ))),
+        }
+    }
+
+    /// Returns a [`CosmosMsg`] transferring the amount of the token to `to`.
+    /// See [`Token::transfer_msg`].
+    pub fn into_transfer_msg(self, to: &Addr) -> StdResult<CosmosMsg> {
+        self.token.transfer_msg(to, self.amount)
+    }
+
+    /// Adds the amount of `other` to the amount of `self`. Fails if the tokens
+    /// differ or on overflow.
+    pub fn checked_add(&self, other: &TokenAmount) -> StdResult<Self> {
+        self.assert_same_token(other)?;
+        Ok(Self::new(
+            self.token.clone(),
+            self.amount.checked_add(other.amount)?,
+        ))
+    }
+
+    /// Subtracts the amount of `other` from the amount of `self`. Fails if the
+    /// tokens differ or on underflow.
+    pub fn checked_sub(&self, other: &TokenAmount) -> StdResult<Self> {
+        self.assert_same_token(other)?;
+        Ok(Self::new(
+            self.token.clone(),
+            self.amount.checked_sub(other.amount)?,
+        ))
+    }
+
+    fn assert_same_token(&self, other: &TokenAmount) -> StdResult<()> {
+        if self.token != other.token {
+            return Err(StdError::generic_err(format!(
+                "Token mismatch: {} and {}",
+                self, other
+            )));
+        }
+        Ok(())
+    }
+}
+
+impl From<Coin> for TokenAmount {
+    fn from(coin: Coin) -> Self {
+        Self::from_coin(coin)
+    }
+}
+
+impl fmt::Display for TokenAmount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.token {
+            Token::Native(denom) => write!(f, "{}{}", self.amount, denom),
+            Token::Cw20(addr) => write!(f, "{}{}", self.amount, addr),
+        }
+    }
+}
+
+/// The number of decimals of the common representation used by
+/// [`NormalizedAmount`].
+pub const NORMALIZED_DECIMALS: u8 = 18;
+
+/// An amount of a [`Token`] along with the number of decimals of the token.
+/// Allows comparing and adding amounts of tokens with different decimals by
+/// converting them to a common representation with [`NORMALIZED_DECIMALS`]
+/// decimals.
+#[cw_serde]
+pub struct NormalizedAmount {
+    /// The token.
+    pub token: Token,
+    /// The amount of `token`, in its own decimals.
+    pub amount: Uint128,
+    /// The number of decimals of `token`.
+    pub decimals: u8,
+}
+
+impl NormalizedAmount {
+    /// Create a new NormalizedAmount instance. Fails if `decimals` is larger
+    /// than [`NORMALIZED_DECIMALS`].
+    pub fn new(token: Token, amount: impl Into<Uint128>, decimals: u8) -> StdResult<Self> {
+        scaling_factor(decimals)?;
+        Ok(Self {
+            token,
+            amount: amount.into(),
+            decimals,
+        })
+    }
+
+    /// Creates a NormalizedAmount from an amount in the common representation
+    /// with [`NORMALIZED_DECIMALS`] decimals, rounding down to the decimals of
+    /// the token.
+    pub fn from_normalized(token: Token, normalized: Uint256, decimals: u8) -> StdResult<Self> {
+        let amount = normalized.checked_div(scaling_factor(decimals)?)?;
+        Self::new(token, Uint128::try_from(amount)?, decimals)
+    }
+
+    /// Returns the amount in the common representation with
+    /// [`NORMALIZED_DECIMALS`] decimals.
+    pub fn normalized(&self) -> StdResult<Uint256> {
+        Ok(Uint256::from(self.amount).checked_mul(scaling_factor(self.decimals)?)?)
+    }
+
+    /// Returns the sum of the normalized amounts of `self` and `other`.
+    pub fn checked_add(&self, other: &NormalizedAmount) -> StdResult<Uint256> {
+        Ok(self.normalized()?.checked_add(other.normalized()?)?)
+    }
+
+    /// Returns the normalized amount of `self` minus that of `other`.
+    pub fn checked_sub(&self, other: &NormalizedAmount) -> StdResult<Uint256> {
+        Ok(self.normalized()?.checked_sub(other.normalized()?)?)
+    }
+
+    /// Returns the amount as a [`TokenAmount`], dropping the decimals.
+    pub fn to_token_amount(&self) -> TokenAmount {
+        TokenAmount::new(self.token.clone(), self.amount)
+    }
+}
+
+/// Returns the factor to multiply an amount with `decimals` decimals with to
+/// get the amount in the common representation of [`NormalizedAmount`].
+fn scaling_factor(decimals: u8) -> StdResult<Uint256> {
+    let exp = NORMALIZED_DECIMALS.checked_sub(decimals).ok_or_else(|| {
+        StdError::generic_err(format!(
+            "Decimals {} exceed the maximum of {}",
+            decimals, NORMALIZED_DECIMALS
+        ))
+    })?;
+    Ok(Uint256::from(10u8).pow(exp.into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::MockApi;
+    use cosmwasm_std::{from_json, to_json_vec};
+
+    use super::*;
+
+    #[test]
+    fn token_deserializes_from_plain_denom() {
+        let token: Token = from_json(br#""uatom""#).unwrap();
+        assert_eq!(token, Token::Native("uatom".to_string()));
+    }
+
+    #[test]
+    fn token_deserializes_from_tagged_object() {
+        let token: Token = from_json(br#"{"native":"uatom"}"#).unwrap();
+        assert_eq!(token, Token::Native("uatom".to_string()));
+
+        let token: Token = from_json(br#"{"cw20":"addr"}"#).unwrap();
+        assert_eq!(token, Token::Cw20(Addr::unchecked("addr")));
+    }
+
+    #[test]
+    fn token_serde_round_trip() {
+        for token in [
+            Token::Native("uatom".to_string()),
+            Token::Cw20(Addr::unchecked("addr")),
+        ] {
+            let json = to_json_vec(&token).unwrap();
+            assert_eq!(from_json::<Token>(&json).unwrap(), token);
+        }
+    }
+
+    #[test]
+    fn token_rejects_unknown_shapes() {
+        for json in [
+            r#"{"unknown":"uatom"}"#,
+            r#"{"native":"uatom","cw20":"addr"}"#,
+            r#"{}"#,
+            r#"{"native":1}"#,
+            r#"["uatom"]"#,
+            r#"1"#,
+            r#"null"#,
+        ] {
+            assert!(from_json::<Token>(json).is_err(), "{}", json);
+        }
+    }
+
+    #[test]
+    fn token_string_round_trip() {
+        for (token, s) in [
+            (Token::Native("uatom".to_string()), "native:uatom"),
+            (
+                Token::Native("factory/addr/sub:denom".to_string()),
+                "native:factory/addr/sub:denom",
+            ),
+            (Token::Cw20(Addr::unchecked("addr")), "cw20:addr"),
+        ] {
+            assert_eq!(token.to_string(), s);
+            assert_eq!(s.parse::<Token>().unwrap(), token);
+        }
+    }
+
+    #[test]
+    fn token_from_str_rejects_invalid_prefixes() {
+        for s in ["uatom", "Native:uatom", "cw721:addr", ":uatom", ""] {
+            assert!(s.parse::<Token>().is_err(), "{}", s);
+        }
+    }
+
+    #[test]
+    fn normalized_amount_scaling() {
+        let token = Token::Native("uatom".to_string());
+        for (decimals, expected) in [
+            (0u8, Uint256::from(10u128.pow(18))),
+            (6, Uint256::from(10u128.pow(12))),
+            (18, Uint256::one()),
+        ] {
+            let amount = NormalizedAmount::new(token.clone(), 1u128, decimals).unwrap();
+            assert_eq!(amount.normalized().unwrap(), expected);
+
+            let amount =
+                NormalizedAmount::from_normalized(token.clone(), expected, decimals).unwrap();
+            assert_eq!(amount.amount, Uint128::one());
+        }
+
+        assert!(NormalizedAmount::new(token.clone(), 1u128, 19).is_err());
+        assert!(NormalizedAmount::from_normalized(token, Uint256::one(), 19).is_err());
+    }
+
+    #[test]
+    fn native_denom_length_bounds() {
+        let api = MockApi::default();
+        for len in [3, 128] {
+            let denom = "u".repeat(len);
+            let token = TokenUnchecked::Native(denom.clone()).check(&api).unwrap();
+            assert_eq!(token, Token::Native(denom));
+        }
+        for len in [2, 129] {
+            let denom = "u".repeat(len);
+            assert!(TokenUnchecked::Native(denom).check(&api).is_err());
+        }
+    }
+
+    #[cfg(feature = "storage")]
+    mod storage {
+        use cosmwasm_std::testing::MockStorage;
+        use cosmwasm_std::Order;
+        use cw_storage_plus::Map;
+
+        use super::*;
+
+        fn native() -> Token {
+            Token::Native(
+                "ibc/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2".to_string(),
+            )
+        }
+
+        fn cw20() -> Token {
+            Token::Cw20(Addr::unchecked("cw20:addr"))
+        }
+
+        #[test]
+        fn token_key_round_trip() {
+            let map = Map::<TokenKey, u64>::new("tokens");
+            let mut storage = MockStorage::new();
+            map.save(&mut storage, native().into(), &1).unwrap();
+            map.save(&mut storage, cw20().into(), &2).unwrap();
+
+            let entries = map
+                .range(&storage, None, None, Order::Ascending)
+                .collect::<StdResult<Vec<_>>>()
+                .unwrap();
+            assert_eq!(entries, vec![(cw20(), 2), (native(), 1)]);
+        }
+
+        #[test]
+        fn token_key_round_trip_in_tuple() {
+            let map = Map::<(TokenKey, Addr), u64>::new("balances");
+            let mut storage = MockStorage::new();
+            let alice = Addr::unchecked("alice");
+            let bob = Addr::unchecked("bob");
+            map.save(&mut storage, (native().into(), alice.clone()), &1)
+                .unwrap();
+            map.save(&mut storage, (native().into(), bob.clone()), &2)
+                .unwrap();
+            map.save(&mut storage, (cw20().into(), alice.clone()), &3)
+                .unwrap();
+
+            let entries = map
+                .range(&storage, None, None, Order::Ascending)
+                .collect::<StdResult<Vec<_>>>()
+                .unwrap();
+            assert_eq!(
+                entries,
+                vec![
+                    ((cw20(), alice.clone()), 3),
+                    ((native(), alice.clone()), 1),
+                    ((native(), bob.clone()), 2),
+                ]
+            );
+
+            let entries = map
+                .prefix(native().into())
+                .range(&storage, None, None, Order::Ascending)
+                .collect::<StdResult<Vec<_>>>()
+                .unwrap();
+            assert_eq!(entries, vec![(alice, 1), (bob, 2)]);
+        }
+    }
+}
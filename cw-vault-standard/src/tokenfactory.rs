@@ -0,0 +1,62 @@
+use cosmwasm_std::{Addr, CosmosMsg, Uint128};
+pub use osmosis_std::types::cosmos::bank::v1beta1::{DenomUnit, Metadata};
+use osmosis_std::types::cosmos::base::v1beta1::Coin as ProtoCoin;
+use osmosis_std::types::osmosis::tokenfactory::v1beta1::{
+    MsgBurn, MsgCreateDenom, MsgMint, MsgSetDenomMetadata,
+};
+
+/// Returns the full denom of a token created by `creator` through the
+/// TokenFactory module with the given `subdenom`, i.e.
+/// `factory/{creator}/{subdenom}`.
+pub fn full_denom(creator: &Addr, subdenom: &str) -> String {
+    format!("factory/{}/{}", creator, subdenom)
+}
+
+/// Returns a [`CosmosMsg`] creating a new denom with the given `subdenom`. The
+/// full denom of the created token is returned by [`full_denom`].
+pub fn create_denom_msg(sender: &Addr, subdenom: impl Into<String>) -> CosmosMsg {
+    MsgCreateDenom {
+        sender: sender.to_string(),
+        subdenom: subdenom.into(),
+    }
+    .into()
+}
+
+/// Returns a [`CosmosMsg`] minting `amount` of `denom` to `mint_to`. `sender`
+/// must be the admin of the denom.
+pub fn mint_msg(sender: &Addr, denom: &str, amount: Uint128, mint_to: &Addr) -> CosmosMsg {
+    MsgMint {
+        sender: sender.to_string(),
+        amount: Some(proto_coin(denom, amount)),
+        mint_to_address: mint_to.to_string(),
+    }
+    .into()
+}
+
+/// Returns a [`CosmosMsg`] burning `amount` of `denom` from `burn_from`.
+/// `sender` must be the admin of the denom.
+pub fn burn_msg(sender: &Addr, denom: &str, amount: Uint128, burn_from: &Addr) -> CosmosMsg {
+    MsgBurn {
+        sender: sender.to_string(),
+        amount: Some(proto_coin(denom, amount)),
+        burn_from_address: burn_from.to_string(),
+    }
+    .into()
+}
+
+/// Returns a [`CosmosMsg`] setting the bank metadata of a denom. `sender` must
+/// be the admin of the denom given in the `base` field of `metadata`.
+pub fn set_denom_metadata_msg(sender: &Addr, metadata: Metadata) -> CosmosMsg {
+    MsgSetDenomMetadata {
+        sender: sender.to_string(),
+        metadata: Some(metadata),
+    }
+    .into()
+}
+
+fn proto_coin(denom: &str, amount: Uint128) -> ProtoCoin {
+    ProtoCoin {
+        denom: denom.to_string(),
+        amount: amount.to_string(),
+    }
+}
@@ -0,0 +1,253 @@
+use cosmwasm_std::{BlockInfo, Coin, MessageInfo, Timestamp, Uint128};
+
+use crate::{Token, VaultStandardError, VaultStandardResult};
+
+/// Asserts that `actual` is at least `min_out`, if set. Vaults should call this
+/// with the `min_shares_out` field of `Deposit` and the `min_assets_out` field
+/// of `Redeem`.
+pub fn assert_min_out(actual: Uint128, min_out: Option<Uint128>) -> VaultStandardResult<()> {
+    match min_out {
+        Some(min_out) if actual < min_out => {
+            Err(VaultStandardError::MinOutputNotMet { min_out, actual })
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Asserts that `amount` is at least `min`, if set. Vaults should call this
+/// with the `min_deposit` and `min_redeem` fields of their `VaultInfoResponse`.
+pub fn assert_min_amount(amount: Uint128, min: Option<Uint128>) -> VaultStandardResult<()> {
+    match min {
+        Some(min) if amount < min => Err(VaultStandardError::BelowMinimum { min, amount }),
+        _ => Ok(()),
+    }
+}
+
+/// Asserts that the time of `block` is not after `deadline`, if set. Vaults
+/// should call this with the `deadline` field of `Deposit` and `Redeem`.
+pub fn assert_deadline(block: &BlockInfo, deadline: Option<Timestamp>) -> VaultStandardResult<()> {
+    match deadline {
+        Some(deadline) if block.time > deadline => Err(VaultStandardError::DeadlineExceeded {
+            deadline,
+            block_time: block.time,
+        }),
+        _ => Ok(()),
+    }
+}
+
+/// Returns the amount of `base_token` sent in `funds`. If `amount` is set, e.g.
+/// from the `amount` field of `Deposit`, asserts that it is equal to the amount
+/// sent. Vaults should call this with `None` for `DepositAll`. Fails if no
+/// `base_token` was sent, so that vaults don't mint shares for an empty
+/// deposit.
+pub fn assert_deposit_funds(
+    funds: &[Coin],
+    base_token: &str,
+    amount: Option<Uint128>,
+) -> VaultStandardResult<Uint128> {
+    let sent: Uint128 = funds
+        .iter()
+        .filter(|coin| coin.denom == base_token)
+        .map(|coin| coin.amount)
+        .sum();
+    match amount {
+        Some(expected) if expected != sent => Err(VaultStandardError::FundsMismatch {
+            denom: base_token.to_string(),
+            expected,
+            actual: sent,
+        }),
+        _ if sent.is_zero() => Err(VaultStandardError::BelowMinimum {
+            min: Uint128::one(),
+            amount: sent,
+        }),
+        _ => Ok(sent),
+    }
+}
+
+/// Asserts that exactly `amount` of `token` was sent in the funds of `info`.
+/// Fails if `token` is a cw20 token, since those must be sent via `Receive`.
+/// Funds of other tokens are ignored, use [`assert_exact_funds`] to reject
+/// them.
+pub fn assert_correct_funds(
+    info: &MessageInfo,
+    token: &Token,
+    amount: Uint128,
+) -> VaultStandardResult<()> {
+    let denom = match token {
+        Token::Native(denom) => denom,
+        Token::Cw20(_) => {
+            return Err(VaultStandardError::ExpectedCw20Receive {
+                token: token.to_string(),
+            })
+        }
+    };
+    let sent = token.find_in_funds(&info.funds).unwrap_or_default();
+    if sent != amount {
+        return Err(VaultStandardError::FundsMismatch {
+            denom: denom.clone(),
+            expected: amount,
+            actual: sent,
+        });
+    }
+    Ok(())
+}
+
+/// Asserts that the funds of `info` consist of exactly one coin, which is
+/// `amount` of `token`. See [`assert_correct_funds`].
+pub fn assert_exact_funds(
+    info: &MessageInfo,
+    token: &Token,
+    amount: Uint128,
+) -> VaultStandardResult<()> {
+    assert_correct_funds(info, token, amount)?;
+    let denoms: Vec<String> = info
+        .funds
+        .iter()
+        .filter(|coin| !token.matches(coin))
+        .map(|coin| coin.denom.clone())
+        .collect();
+    if !denoms.is_empty() {
+        return Err(VaultStandardError::UnexpectedFunds { denoms });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::{mock_env, mock_info};
+    use cosmwasm_std::{coin, Addr};
+
+    use super::*;
+
+    #[test]
+    fn deposit_funds_returns_sent_amount() {
+        let funds = [coin(100, "uatom"), coin(50, "uosmo")];
+        assert_eq!(
+            assert_deposit_funds(&funds, "uatom", None).unwrap(),
+            Uint128::new(100)
+        );
+        assert_eq!(
+            assert_deposit_funds(&funds, "uatom", Some(Uint128::new(100))).unwrap(),
+            Uint128::new(100)
+        );
+    }
+
+    #[test]
+    fn deposit_funds_rejects_wrong_amount() {
+        let funds = [coin(100, "uatom")];
+        assert_eq!(
+            assert_deposit_funds(&funds, "uatom", Some(Uint128::new(200))).unwrap_err(),
+            VaultStandardError::FundsMismatch {
+                denom: "uatom".to_string(),
+                expected: Uint128::new(200),
+                actual: Uint128::new(100),
+            }
+        );
+    }
+
+    #[test]
+    fn deposit_funds_rejects_empty_deposit() {
+        let expected = VaultStandardError::BelowMinimum {
+            min: Uint128::one(),
+            amount: Uint128::zero(),
+        };
+        let funds = [coin(100, "uosmo")];
+        assert_eq!(
+            assert_deposit_funds(&funds, "uatom", None).unwrap_err(),
+            expected
+        );
+        assert_eq!(
+            assert_deposit_funds(&[], "uatom", Some(Uint128::zero())).unwrap_err(),
+            expected
+        );
+    }
+
+    #[test]
+    fn min_out_is_inclusive() {
+        assert!(assert_min_out(Uint128::new(100), None).is_ok());
+        assert!(assert_min_out(Uint128::new(100), Some(Uint128::new(100))).is_ok());
+        assert_eq!(
+            assert_min_out(Uint128::new(99), Some(Uint128::new(100))).unwrap_err(),
+            VaultStandardError::MinOutputNotMet {
+                min_out: Uint128::new(100),
+                actual: Uint128::new(99),
+            }
+        );
+    }
+
+    #[test]
+    fn deadline_is_inclusive() {
+        let block = mock_env().block;
+        assert!(assert_deadline(&block, None).is_ok());
+        assert!(assert_deadline(&block, Some(block.time)).is_ok());
+        assert_eq!(
+            assert_deadline(&block, Some(block.time.minus_seconds(1))).unwrap_err(),
+            VaultStandardError::DeadlineExceeded {
+                deadline: block.time.minus_seconds(1),
+                block_time: block.time,
+            }
+        );
+    }
+
+    #[test]
+    fn correct_funds_accepts_exact_amount() {
+        let token = Token::Native("uatom".to_string());
+        let info = mock_info("sender", &[coin(100, "uatom"), coin(50, "uosmo")]);
+        assert!(assert_correct_funds(&info, &token, Uint128::new(100)).is_ok());
+    }
+
+    #[test]
+    fn correct_funds_rejects_cw20() {
+        let token = Token::Cw20(Addr::unchecked("cw20"));
+        let info = mock_info("sender", &[]);
+        assert_eq!(
+            assert_correct_funds(&info, &token, Uint128::new(100)).unwrap_err(),
+            VaultStandardError::ExpectedCw20Receive {
+                token: "cw20:cw20".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn correct_funds_rejects_wrong_amount() {
+        let token = Token::Native("uatom".to_string());
+        let info = mock_info("sender", &[coin(50, "uatom")]);
+        assert_eq!(
+            assert_correct_funds(&info, &token, Uint128::new(100)).unwrap_err(),
+            VaultStandardError::FundsMismatch {
+                denom: "uatom".to_string(),
+                expected: Uint128::new(100),
+                actual: Uint128::new(50),
+            }
+        );
+    }
+
+    #[test]
+    fn correct_funds_rejects_missing_coin() {
+        let token = Token::Native("uatom".to_string());
+        let info = mock_info("sender", &[coin(100, "uosmo")]);
+        assert_eq!(
+            assert_correct_funds(&info, &token, Uint128::new(100)).unwrap_err(),
+            VaultStandardError::FundsMismatch {
+                denom: "uatom".to_string(),
+                expected: Uint128::new(100),
+                actual: Uint128::zero(),
+            }
+        );
+    }
+
+    #[test]
+    fn exact_funds_rejects_extra_denoms() {
+        let token = Token::Native("uatom".to_string());
+        let info = mock_info("sender", &[coin(100, "uatom")]);
+        assert!(assert_exact_funds(&info, &token, Uint128::new(100)).is_ok());
+
+        let info = mock_info("sender", &[coin(100, "uatom"), coin(50, "uosmo")]);
+        assert_eq!(
+            assert_exact_funds(&info, &token, Uint128::new(100)).unwrap_err(),
+            VaultStandardError::UnexpectedFunds {
+                denoms: vec!["uosmo".to_string()],
+            }
+        );
+    }
+}
@@ -1,10 +1,10 @@
-use cosmwasm_std::{coin, Coin, Uint128};
+use cosmwasm_std::{coin, Addr, Coin, Uint128};
 use cw_it::helpers::Unwrap;
 use cw_it::test_tube::{Account, Runner, SigningAccount};
 
-use cw_vault_standard::extensions::force_unlock::ForceUnlockExecuteMsg;
+use cw_vault_standard::extensions::force_unlock::{ForceUnlockExecuteMsg, ForceUnlockQueryMsg};
 use cw_vault_standard::msg::VaultStandardExecuteMsg as ExecuteMsg;
-use cw_vault_standard::ExtensionExecuteMsg;
+use cw_vault_standard::{ExtensionExecuteMsg, ExtensionQueryMsg, VaultStandardQueryMsg};
 
 use super::CwVaultStandardRobot;
 
@@ -107,4 +107,20 @@ pub trait ForceUnlockVaultRobot<'a, R: Runner<'a> + 'a>: CwVaultStandardRobot<'a
         ));
         self
     }
+
+    /// Queries the vault for the force withdraw whitelist (with optional pagination).
+    fn query_force_withdraw_whitelist(
+        &self,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> Vec<Addr> {
+        self.wasm()
+            .query(
+                &self.vault_addr(),
+                &VaultStandardQueryMsg::VaultExtension(ExtensionQueryMsg::ForceUnlock(
+                    ForceUnlockQueryMsg::ForceWithdrawWhitelist { start_after, limit },
+                )),
+            )
+            .unwrap()
+    }
 }
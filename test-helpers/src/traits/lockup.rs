@@ -3,7 +3,9 @@ use cw_it::helpers::Unwrap;
 use cw_it::test_tube::{Account, Runner, SigningAccount};
 
 use cw_utils::Duration;
-use cw_vault_standard::extensions::lockup::{LockupExecuteMsg, LockupQueryMsg, UnlockingPosition};
+use cw_vault_standard::extensions::lockup::{
+    LockupExecuteMsg, LockupQueryMsg, TotalLockupStateResponse, UnlockingPosition,
+};
 use cw_vault_standard::msg::VaultStandardExecuteMsg as ExecuteMsg;
 use cw_vault_standard::{ExtensionExecuteMsg, ExtensionQueryMsg, VaultStandardQueryMsg};
 
@@ -22,6 +24,7 @@ pub trait LockedVaultRobot<'a, R: Runner<'a> + 'a>: CwVaultStandardRobot<'a, R>
             &self.vault_addr(),
             &ExecuteMsg::VaultExtension(ExtensionExecuteMsg::Lockup(LockupExecuteMsg::Unlock {
                 amount: amount.into(),
+                tier: None,
             })),
             funds,
             signer,
@@ -74,6 +77,29 @@ pub trait LockedVaultRobot<'a, R: Runner<'a> + 'a>: CwVaultStandardRobot<'a, R>
         self
     }
 
+    /// Calls `ExecuteMsg::WithdrawAllUnlocked` to withdraw tokens from multiple matured lockup
+    /// positions, or all of them if `lockup_ids` is `None`.
+    fn withdraw_all_unlocked(
+        &self,
+        lockup_ids: Option<Vec<u64>>,
+        recipient: Option<String>,
+        unwrap_choice: Unwrap,
+        signer: &SigningAccount,
+    ) -> &Self {
+        unwrap_choice.unwrap(self.wasm().execute(
+            &self.vault_addr(),
+            &ExecuteMsg::VaultExtension(ExtensionExecuteMsg::Lockup(
+                LockupExecuteMsg::WithdrawAllUnlocked {
+                    lockup_ids,
+                    recipient,
+                },
+            )),
+            &[],
+            signer,
+        ));
+        self
+    }
+
     /// Queries the vault for all unlocking positions of the given address (with optional pagination).
     fn query_unlocking_positions(
         &self,
@@ -86,7 +112,7 @@ pub trait LockedVaultRobot<'a, R: Runner<'a> + 'a>: CwVaultStandardRobot<'a, R>
                 &self.vault_addr(),
                 &VaultStandardQueryMsg::VaultExtension(ExtensionQueryMsg::Lockup(
                     LockupQueryMsg::UnlockingPositions {
-                        owner: address.into(),
+                        owner: Some(address.into()),
                         start_after,
                         limit,
                     },
@@ -119,6 +145,18 @@ pub trait LockedVaultRobot<'a, R: Runner<'a> + 'a>: CwVaultStandardRobot<'a, R>
             .unwrap()
     }
 
+    /// Queries the vault for the aggregate lockup state.
+    fn query_total_lockup_state(&self) -> TotalLockupStateResponse {
+        self.wasm()
+            .query(
+                &self.vault_addr(),
+                &VaultStandardQueryMsg::VaultExtension(ExtensionQueryMsg::Lockup(
+                    LockupQueryMsg::TotalLockupState {},
+                )),
+            )
+            .unwrap()
+    }
+
     /// Asserts that the number of unlocking positions in the vault is equal to the given value.
     fn assert_number_of_unlocking_positions(
         &self,
@@ -47,7 +47,13 @@ pub trait CwVaultStandardRobot<'a, R: Runner<'a> + 'a>: TestRobot<'a, R> {
         let amount: Uint128 = amount.into();
         unwrap_choice.unwrap(self.wasm().execute(
             &self.vault_addr(),
-            &ExecuteMsg::<Empty>::Deposit { amount, recipient },
+            &ExecuteMsg::<Empty>::Deposit {
+                amount,
+                recipient: recipient.map(Into::into),
+                callback: None,
+                min_shares_out: None,
+                deadline: None,
+            },
             funds,
             signer,
         ));
@@ -96,7 +102,13 @@ pub trait CwVaultStandardRobot<'a, R: Runner<'a> + 'a>: TestRobot<'a, R> {
     ) -> &Self {
         unwrap_choice.unwrap(self.wasm().execute(
             &self.vault_addr(),
-            &ExecuteMsg::<Empty>::Redeem { amount, recipient },
+            &ExecuteMsg::<Empty>::Redeem {
+                amount,
+                recipient: recipient.map(Into::into),
+                callback: None,
+                min_assets_out: None,
+                deadline: None,
+            },
             funds,
             signer,
         ));